@@ -2,10 +2,24 @@
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+    L68
+    L30
+    R48
+    L5
+    R60
+    L55
+    L1
+    L99
+    R14
+    L82
+"#;
+
 struct State {
     exactly_zero_count: u32, // part 1
     zero_count: u32,         // part 2
@@ -22,10 +36,10 @@ impl State {
     }
 }
 
-fn simulate_dial_position(input: &str) -> State {
-    return input.split("\n").map(|line| line.trim()).fold(
-        State::new(),
-        |mut accumulator, current| {
+fn simulate_dial_position(input: &Input) -> State {
+    return input
+        .lines()
+        .fold(State::new(), |mut accumulator, current| {
             if let Some(direction_char) = current.chars().nth(0)
                 && let Ok(rotations) = &current[1..].parse::<i32>()
             {
@@ -49,19 +63,19 @@ fn simulate_dial_position(input: &str) -> State {
                 );
             }
             return accumulator;
-        },
-    );
+        });
 }
 
-fn get_part1_password(input: &str) -> u32 {
+fn get_part1_password(input: &Input) -> u32 {
     return simulate_dial_position(input).exactly_zero_count;
 }
-fn get_part2_password(input: &str) -> u32 {
+fn get_part2_password(input: &Input) -> u32 {
     return simulate_dial_position(input).zero_count;
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 1).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 1, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     println!("The part 1 password is: {}", get_part1_password(&input));
     println!("The part 2 password is: {}", get_part2_password(&input));
 }
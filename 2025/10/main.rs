@@ -4,10 +4,17 @@ use good_lp::{Expression, ProblemVariables, Solution, SolverModel, solvers::scip
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+"#;
+
 #[derive(Debug)]
 enum IndicatorState {
     On,
@@ -192,10 +199,9 @@ fn minimum_button_presses_to_power(machine: &Machine) -> u32 {
     return minimum_button_presses;
 }
 
-fn parse_machines(input: &str) -> impl Iterator<Item = Machine> + Clone {
+fn parse_machines(input: &Input) -> impl Iterator<Item = Machine> + Clone {
     input
-        .split("\n")
-        .filter_map(|line| (!line.is_empty()).then_some(line.trim()))
+        .lines()
         .enumerate()
         .map(|(number, line)| Machine::parse(number, line))
 }
@@ -213,7 +219,8 @@ fn power_all_machines(machines: impl Iterator<Item = Machine>) -> u32 {
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 10).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 10, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let machines = parse_machines(&input);
     println!(
         "The minimum number of button presses to enable all the machines is: {}",
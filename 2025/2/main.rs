@@ -4,10 +4,13 @@ use std::collections::HashSet;
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
 struct Range {
     lower: u64,
     upper: u64,
@@ -36,10 +39,11 @@ impl Range {
     }
 }
 
-fn find_invalid_ids(input: &str, invalid_id_fn: InvalidIdFn) -> u64 {
+fn find_invalid_ids(input: &Input, invalid_id_fn: InvalidIdFn) -> u64 {
     let mut set = HashSet::new(); // protect against overlapping ranges
     input
-        .split(",")
+        .lines()
+        .flat_map(|line| line.split(","))
         .map(|range| {
             Range::new(range)
                 .map(|range| range.sum_invalid_ids(invalid_id_fn, &mut set))
@@ -71,7 +75,8 @@ fn is_invalid_id_part2(id: &str) -> bool {
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 2).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 2, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     println!(
         "The sum of all invalid ids in part 1 is: {}",
         find_invalid_ids(&input, is_invalid_id_part1)
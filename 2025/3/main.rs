@@ -2,10 +2,18 @@
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+    987654321111111
+    811111111111119
+    234234234234278
+    818181911112111
+"#;
+
 fn get_max_digit(slice: &str) -> Option<(usize, char)> {
     slice
         .chars()
@@ -40,20 +48,18 @@ fn get_max_bank_joltage(bank: &str, num_cells: u8) -> u64 {
         .expect("there should not be any non-digit characters in each input line");
 }
 
-fn get_max_joltage(input: &str, num_cells: u8) -> u64 {
+fn get_max_joltage(input: &Input, num_cells: u8) -> u64 {
     input
         // one bank per line
-        .split("\n")
-        // discard empty lines
-        .map(|bank| bank.trim())
-        .filter(|bank| !bank.is_empty())
+        .lines()
         // compute sum
         .map(|bank| get_max_bank_joltage(bank, num_cells))
         .sum()
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 3).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 3, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     println!(
         "Largest joltage using 2 cells: {}",
         get_max_joltage(&input, 2)
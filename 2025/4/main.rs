@@ -2,29 +2,25 @@
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
-use ndarray::{Array2, ArrayBase, Zip, array};
+use ndarray::{Array2, Zip, array};
 use ndarray_conv::*;
 
-fn parse_input(input: &str) -> Array2<u8> {
-    let mut rows = 1;
-    let array = ArrayBase::from_iter(input.trim().chars().filter_map(|char| match char {
-        '.' => Some(0),
-        '@' => Some(1),
-        '\n' => {
-            rows += 1;
-            None
-        }
-        c => panic!("should never encounter char '{}' in the input", c),
-    }));
-    // subtract the number of rows as each row adds one extra character for the newline
-    let columns = (input.len() - rows) / rows;
-    return array
-        .into_shape_with_order((rows, columns))
-        .expect("calculated dimensions should always match data");
+static EXAMPLE_INPUT: &str = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n";
+
+fn parse_input(input: &Input) -> Array2<u8> {
+    input
+        .grid::<char>()
+        .expect("every row should have the same number of columns")
+        .mapv(|char| match char {
+            '.' => 0,
+            '@' => 1,
+            c => panic!("should never encounter char '{}' in the input", c),
+        })
 }
 
 fn get_num_directly_accessible_rolls(rolls: &Array2<u8>) -> (Array2<u8>, u32) {
@@ -67,7 +63,8 @@ fn get_num_accessible_rolls(rolls: Array2<u8>) -> u32 {
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 4).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 4, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let rolls = parse_input(&input);
     println!(
         "Number of directly accessible paper rolls: {}",
@@ -4,10 +4,25 @@ use std::{cmp, str};
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+    3-5
+    10-14
+    16-20
+    12-18
+
+    1
+    5
+    8
+    11
+    17
+    32
+"#;
+
 // when `PartialOrd` is derived on structs it compares fields in lexicographical
 // order which is desirable here to sort by lower bound before upper bound
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -52,14 +67,8 @@ impl Range {
     }
 }
 
-fn count_available_fresh_ingredients<'a>(
-    ranges: &Vec<Range>,
-    ingredients: impl Iterator<Item = &'a str>,
-) -> u64 {
-    return ingredients.fold(0, |mut accumulator, ingredient_str| {
-        let ingredient = ingredient_str
-            .parse::<u64>()
-            .expect("ingredient ids should always be valid integers");
+fn count_available_fresh_ingredients(ranges: &Vec<Range>, ingredients: &[u64]) -> u64 {
+    return ingredients.iter().fold(0, |mut accumulator, &ingredient| {
         if ranges.iter().any(|range| range.contains(ingredient)) {
             accumulator += 1
         }
@@ -87,38 +96,32 @@ fn count_all_fresh_ingredients(mut ranges: Vec<Range>) -> u64 {
     merged_ranges.iter().map(|range| range.size()).sum()
 }
 
-fn parse_input(input: &str) -> (Vec<Range>, impl Iterator<Item = &str>) {
-    let mut inputs = input.split("\n\n");
+fn parse_input(input: &Input) -> (Vec<Range>, Vec<u64>) {
+    let mut inputs = input.paragraphs();
     let ranges = inputs
         .next()
         .expect("ranges should always be located before the empty newline")
-        .split("\n")
-        .filter_map(|ingredient_str| {
-            let trimmed_str = ingredient_str.trim();
-            (!trimmed_str.is_empty()).then_some(trimmed_str)
-        })
+        .lines()
         .map(|range| Range::new(range))
         .collect::<Vec<_>>();
 
     let ingredients = inputs
         .next()
         .expect("available ingredients should always be located after the empty newline")
-        .split("\n")
-        .filter_map(|ingredient_str| {
-            let trimmed_str = ingredient_str.trim();
-            (!trimmed_str.is_empty()).then_some(trimmed_str)
-        });
+        .parse_lines::<u64>()
+        .expect("ingredient ids should always be valid integers");
 
     return (ranges, ingredients);
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 5).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 5, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let (ranges, ingredients) = parse_input(&input);
 
     println!(
         "Available fresh ingredients: {}",
-        count_available_fresh_ingredients(&ranges, ingredients)
+        count_available_fresh_ingredients(&ranges, &ingredients)
     );
 
     println!(
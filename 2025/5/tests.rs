@@ -1,19 +1,5 @@
 // https://adventofcode.com/2025/day/5
 
-static EXAMPLE_INPUT: &str = r#"
-    3-5
-    10-14
-    16-20
-    12-18
-
-    1
-    5
-    8
-    11
-    17
-    32
-"#;
-
 use super::*;
 
 #[test]
@@ -67,12 +53,12 @@ fn merge_ranges() {
 
 #[test]
 fn part1_example() {
-    let (ranges, ingredients) = parse_input(EXAMPLE_INPUT);
-    assert_eq!(count_available_fresh_ingredients(&ranges, ingredients), 3)
+    let (ranges, ingredients) = parse_input(&Input::example(EXAMPLE_INPUT));
+    assert_eq!(count_available_fresh_ingredients(&ranges, &ingredients), 3)
 }
 
 #[test]
 fn part2_example() {
-    let (ranges, _) = parse_input(EXAMPLE_INPUT);
+    let (ranges, _) = parse_input(&Input::example(EXAMPLE_INPUT));
     assert_eq!(count_all_fresh_ingredients(ranges), 14)
 }
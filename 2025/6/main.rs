@@ -6,10 +6,18 @@ use ndarray::{Array2, ArrayBase};
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+"#;
+
 #[derive(Debug)]
 enum Operator {
     Add,
@@ -95,12 +103,9 @@ impl<'a> Iterator for ProblemIterator<'a> {
     }
 }
 
-fn part1(input: &str) -> u64 {
+fn part1(input: &Input) -> u64 {
     let mut num_rows = 0;
-    let mut rows = input
-        .split('\n')
-        .filter_map(|row| (!row.is_empty()).then_some(row.trim()))
-        .rev();
+    let mut rows = input.lines().map(|row| row.trim()).rev();
 
     let operators = rows
         .next()
@@ -137,16 +142,14 @@ fn part1(input: &str) -> u64 {
         .sum();
 }
 
-fn part2(input: &str) -> u64 {
-    let row_iterators: Vec<_> = input
-        .split('\n')
-        .filter_map(|row| (!row.is_empty()).then_some(row.chars()))
-        .collect();
+fn part2(input: &Input) -> u64 {
+    let row_iterators: Vec<_> = input.lines().map(|row| row.chars()).collect();
     ProblemIterator::new(row_iterators).sum()
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 6).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 6, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
 
     println!("The part 1 grand total is: {}", part1(&input));
     println!("The part 2 grand total is: {}", part2(&input));
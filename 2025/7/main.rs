@@ -1,13 +1,33 @@
 // https://adventofcode.com/2025/day/7
 
-use ndarray::{Array2, ArrayBase};
+use ndarray::Array2;
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+"#;
+
 #[derive(PartialEq, Copy, Clone)]
 enum Tachyon {
     Splitter,
@@ -16,6 +36,19 @@ enum Tachyon {
     Empty,
 }
 
+impl TryFrom<char> for Tachyon {
+    type Error = char;
+
+    fn try_from(char: char) -> Result<Self, char> {
+        match char {
+            '.' => Ok(Tachyon::Empty),
+            'S' => Ok(Tachyon::Start),
+            '^' => Ok(Tachyon::Splitter),
+            c => Err(c),
+        }
+    }
+}
+
 struct TachyonManifold(Array2<Tachyon>);
 
 struct TachyonSimulationResult {
@@ -24,24 +57,12 @@ struct TachyonSimulationResult {
 }
 
 impl TachyonManifold {
-    fn new(string: &str) -> Self {
-        let mut rows = 1;
-        let array = ArrayBase::from_iter(string.trim().chars().filter_map(|char| match char {
-            '.' => Some(Tachyon::Empty),
-            'S' => Some(Tachyon::Start),
-            '^' => Some(Tachyon::Splitter),
-            '\n' => {
-                rows += 1;
-                None
-            }
-            c => panic!("should never encounter char '{}' in the input", c),
-        }));
-        let columns = (string.len() - rows) / rows;
-        return TachyonManifold(
-            array
-                .into_shape_with_order((rows, columns))
-                .expect("calculated dimensions should always match data"),
-        );
+    fn new(input: &Input) -> Self {
+        TachyonManifold(
+            input
+                .grid()
+                .expect("the manifold should be a rectangle of '.', 'S' and '^'"),
+        )
     }
 
     fn simulate(&mut self) -> TachyonSimulationResult {
@@ -139,7 +160,8 @@ impl TachyonManifold {
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 7).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 7, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let mut manifold = TachyonManifold::new(&input);
     let result = manifold.simulate();
     println!("Number of beam splits: {}", result.num_beam_splits);
@@ -5,10 +5,34 @@ use std::collections::{HashMap, HashSet};
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+    162,817,812
+    57,618,57
+    906,360,560
+    592,479,940
+    352,342,300
+    466,668,158
+    542,29,236
+    431,825,988
+    739,650,466
+    52,470,668
+    216,146,977
+    819,987,18
+    117,168,530
+    805,96,715
+    346,949,466
+    970,615,88
+    941,993,340
+    862,61,35
+    984,92,344
+    425,690,689
+"#;
+
 #[derive(Debug, Copy, Clone)]
 struct JunctionBox {
     x: i64,
@@ -58,14 +82,10 @@ fn get_all_graphs(adjacency_list: &AdjacencyList) -> Vec<HashSet<usize>> {
     return graphs;
 }
 
-fn junction_boxes(input: &str) -> impl Iterator<Item = JunctionBox> + Clone {
-    input
-        .split("\n")
-        .filter_map(|line| (!line.is_empty()).then_some(line.trim()))
-        .enumerate()
-        .map(|(index, line)| {
-            JunctionBox::parse(line, index).expect(&format!("should parse '{line}' successfully"))
-        })
+fn junction_boxes(input: &Input) -> impl Iterator<Item = JunctionBox> + Clone {
+    input.lines().enumerate().map(|(index, line)| {
+        JunctionBox::parse(line, index).expect(&format!("should parse '{line}' successfully"))
+    })
 }
 
 fn closest_junction_boxes(
@@ -145,7 +165,8 @@ fn get_multiplied_fully_connecting_x_coordinates<'a>(
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 8).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 8, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let junction_boxes = junction_boxes(&input);
     let closest_junction_boxes = closest_junction_boxes(junction_boxes.clone());
     println!(
@@ -4,10 +4,22 @@ use std::cmp::{Ordering, max, min};
 
 #[path = "../../helpers.rs"]
 mod helpers;
+use helpers::Input;
 
 #[cfg(test)]
 mod tests;
 
+static EXAMPLE_INPUT: &str = r#"
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+"#;
+
 enum ComparisonAxis {
     TopLeftBottomRight(i64, i64),
     TopRightBottomLeft,
@@ -101,15 +113,15 @@ fn max_rectangle_area(red_tiles: impl Iterator<Item = RedTile> + Clone) -> i64 {
     );
 }
 
-fn get_red_tiles(input: &str) -> impl Iterator<Item = RedTile> + Clone {
+fn get_red_tiles(input: &Input) -> impl Iterator<Item = RedTile> + Clone {
     return input
-        .split("\n")
-        .filter_map(|line| (!line.is_empty()).then_some(line.trim()))
+        .lines()
         .map(|line| RedTile::parse(line).expect(&format!("{line} should parse")));
 }
 
 fn main() {
-    let input = helpers::get_input(2025, 9).unwrap();
+    let input =
+        helpers::get_input_from_args(2025, 9, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
     let maximum_area = max_rectangle_area(get_red_tiles(&input));
 
     println!("The largest rectangle possible is: {maximum_area}");
@@ -1,46 +1,174 @@
+mod helpers;
+
+#[path = "gen_boilerplate/manifest.rs"]
+mod manifest;
+
+#[path = "gen_boilerplate/readme.rs"]
+mod readme;
+
+#[path = "gen_boilerplate/scaffold.rs"]
+mod scaffold;
+
+#[path = "gen_boilerplate/template.rs"]
+mod template;
+
+#[cfg(test)]
+#[path = "gen_boilerplate/tests.rs"]
+mod tests;
+
+use clap::{Args, Parser, Subcommand};
 use git2::{Repository, Status, StatusOptions};
+use std::collections::HashMap;
 use std::fs;
-use toml_edit::{ArrayOfTables, DocumentMut, Item, Table};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
 
-fn get_main_rs_content(year: &str, day: &str) -> String {
-    return format!(
-        r#"
-        // https://adventofcode.com/{year}/day/{day}
+/// everything that could be extracted from the puzzle page to generate real tests
+struct Example {
+    title: Option<String>,
+    input: Option<String>,
+    answers: Vec<Option<String>>,
+}
 
-        #[path = "../../helpers.rs"]
-        mod helpers;
+impl Example {
+    fn answer(&self, part: usize) -> Option<&str> {
+        self.answers.get(part - 1)?.as_deref()
+    }
+}
 
-        #[cfg(test)]
-        mod tests;
+/// the return type of a part's solution based on its example answer
+fn answer_type(answer: Option<&str>) -> &'static str {
+    match answer {
+        Some(answer) if answer.parse::<u64>().is_err() && answer.parse::<i64>().is_ok() => "i64",
+        Some(answer) if answer.parse::<i64>().is_err() => "String",
+        _ => "u64",
+    }
+}
 
-        fn main() {{
-            let input = helpers::get_input({year}, {day}).unwrap();
-            println!("hello world");
-        }}
-        "#
-    );
+/// the values which templates can use, see [`template::render`]
+fn placeholders(day: helpers::Day, example: &Example) -> HashMap<&'static str, Option<String>> {
+    let (year, number) = (day.year(), day.number());
+    HashMap::from([
+        ("year", Some(year.to_string())),
+        ("day", Some(number.to_string())),
+        ("title", example.title.clone()),
+        (
+            "url",
+            Some(format!("https://adventofcode.com/{year}/day/{number}")),
+        ),
+        (
+            "example",
+            Some(example_input_literal(
+                example.input.as_deref().unwrap_or_default(),
+            )),
+        ),
+        // the last day only has one part
+        ("part2", (day.parts() == 2).then(|| "true".to_owned())),
+        (
+            "part1_type",
+            Some(answer_type(example.answer(1)).to_owned()),
+        ),
+        (
+            "part2_type",
+            Some(answer_type(example.answer(2)).to_owned()),
+        ),
+        (
+            "part1_answer",
+            example.answer(1).map(example_answer_literal),
+        ),
+        (
+            "part2_answer",
+            example.answer(2).map(example_answer_literal),
+        ),
+    ])
 }
 
-fn get_test_rs_content(year: &str, day: &str) -> String {
-    return format!(
-        r#"
-        // https://adventofcode.com/{year}/day/{day}
+/// formats generated code like `cargo fmt` does, or `None` if rustfmt is not
+/// installed or cannot parse it
+fn try_rustfmt(code: &str) -> Option<String> {
+    let mut rustfmt = process::Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::null())
+        .spawn()
+        .ok()?;
+    rustfmt.stdin.take()?.write_all(code.as_bytes()).ok()?;
+    let output = rustfmt.wait_with_output().ok()?;
+    match output.status.success() {
+        true => String::from_utf8(output.stdout).ok(),
+        false => None,
+    }
+}
 
-        static EXAMPLE_INPUT: &str = "";
+/// the built in templates are already formatted but other templates may not
+/// be. Code which rustfmt cannot parse is reported by `cargo check` instead
+fn rustfmt(code: String) -> String {
+    try_rustfmt(&code).unwrap_or(code)
+}
 
-        use super::*;
+fn get_main_rs_content(
+    day: helpers::Day,
+    example: &Example,
+    flavor: &str,
+) -> Result<String, helpers::AocError> {
+    template::render(
+        &template::load(flavor, "main.rs")?,
+        &placeholders(day, example),
+    )
+}
 
-        #[test]
-        fn part1_example() {{
-            assert_eq!(true, true);
-        }}
+/// formats an example as a string literal in the same style as the existing
+/// tests. Multi-line examples are indented inside a raw string (which
+/// [`helpers::Input::example`] dedents) unless leading whitespace is significant
+fn example_input_literal(input: &str) -> String {
+    let input = input.trim_end_matches('\n');
+    // dedenting would remove whitespace which every line starts with
+    if !input.contains('\n')
+        || input
+            .lines()
+            .all(|line| line.starts_with(char::is_whitespace))
+    {
+        return format!("{input:?}");
+    }
+    let indent = match input
+        .lines()
+        .any(|line| line.starts_with(char::is_whitespace))
+    {
+        true => "",
+        false => "    ",
+    };
+    // use enough hashes that the example cannot terminate the raw string early
+    let hashes = (1..)
+        .map(|count| "#".repeat(count))
+        .find(|hashes| !input.contains(&format!("\"{hashes}")))
+        .expect("some number of hashes should always be unused");
+    let lines = input
+        .lines()
+        .map(|line| format!("{indent}{line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("r{hashes}\"\n{lines}\n\"{hashes}")
+}
 
-        #[test]
-        fn part2_example() {{
-            assert_eq!(true, true);
-        }}
-        "#
-    );
+fn example_answer_literal(answer: &str) -> String {
+    match answer_type(Some(answer)) {
+        "String" => format!("{answer:?}"),
+        _ => answer.to_owned(),
+    }
+}
+
+fn get_test_rs_content(
+    day: helpers::Day,
+    example: &Example,
+    flavor: &str,
+) -> Result<String, helpers::AocError> {
+    template::render(
+        &template::load(flavor, "tests.rs")?,
+        &placeholders(day, example),
+    )
 }
 
 fn git_repo_has_uncommitted_changes(repo: &Repository) -> Result<bool, git2::Error> {
@@ -52,56 +180,599 @@ fn git_repo_has_uncommitted_changes(repo: &Repository) -> Result<bool, git2::Err
         .any(|file| file.status() != Status::CURRENT))
 }
 
-struct Bin {
-    name: String,
-    path: String,
+/// tools for solving Advent of Code in this repository
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// a day of an event given on the command line (Ex: 2025 5)
+#[derive(Args)]
+struct DayArg {
+    /// the year of the event, from 2015 onwards
+    #[arg(value_parser = parse_event)]
+    year: helpers::Event,
+    /// the day of the event, from 1 to 25 (or 12 from 2025 onwards)
+    day: u8,
+}
+
+impl DayArg {
+    /// exits if the day is not part of the event
+    fn validate(&self) -> helpers::Day {
+        self.year.day(self.day).unwrap_or_else(|error| error.exit())
+    }
 }
 
-fn update_cargo_toml(new_bin: Bin) {
-    let cargo_toml_str = fs::read_to_string("./cargo.toml").unwrap();
-    let mut cargo_toml = cargo_toml_str.parse::<DocumentMut>().expect("invalid doc");
-    let bins = cargo_toml
-        .entry("bin")
-        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
-        .as_array_of_tables_mut()
-        .unwrap();
+fn parse_event(year: &str) -> Result<helpers::Event, String> {
+    helpers::Event::parse(year).map_err(|error| error.to_string())
+}
 
-    // Create a new [[bin]] table
-    let mut bin_table = Table::new();
-    bin_table["name"] = Item::Value(new_bin.name.into());
-    bin_table["path"] = Item::Value(new_bin.path.into());
+#[derive(Subcommand)]
+enum Command {
+    /// generates the code and tests for a day and adds it to Cargo.toml
+    New {
+        #[command(flatten)]
+        day: DayArg,
+        /// overwrite a day which has already been generated, after showing what changes
+        #[arg(long)]
+        force: bool,
+        /// the templates to start from: plain, grid, graph, ilp or a directory in templates/
+        #[arg(long, default_value = "plain")]
+        template: String,
+    },
+    /// caches the input for a day
+    Fetch {
+        #[command(flatten)]
+        day: DayArg,
+        /// wait for the puzzle to unlock instead of failing
+        #[arg(long)]
+        wait: bool,
+    },
+    /// downloads the description of a day again and saves it as markdown next
+    /// to its code
+    Puzzle {
+        #[command(flatten)]
+        day: DayArg,
+        /// wait for the puzzle to unlock instead of failing
+        #[arg(long)]
+        wait: bool,
+    },
+    /// solves a day, passing any arguments after -- to it (Ex: -- --example)
+    Run {
+        #[command(flatten)]
+        day: DayArg,
+        #[arg(long)]
+        release: bool,
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// runs the tests of a day, passing any arguments after -- to them
+    Test {
+        #[command(flatten)]
+        day: DayArg,
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// submits an answer, unless the answer ledger already knows it is wrong
+    Submit {
+        #[command(flatten)]
+        day: DayArg,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+    /// times how long a release build of a day takes to solve its input
+    Bench {
+        #[command(flatten)]
+        day: DayArg,
+        #[arg(long, default_value_t = 10)]
+        runs: u32,
+    },
+    /// marks the stars collected on AoC in the table at the top of the README
+    Readme {
+        #[arg(value_parser = parse_event)]
+        year: helpers::Event,
+    },
+    /// inspects or clears the pages cached from AoC
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// shows a private leaderboard, which is fetched at most once every 15 minutes
+    Leaderboard {
+        #[arg(value_parser = parse_event)]
+        year: helpers::Event,
+        /// the number at the end of the leaderboard's url
+        id: u64,
+    },
+    /// shows the personal times, ranks and scores for a year
+    Stats {
+        #[arg(value_parser = parse_event)]
+        year: helpers::Event,
+    },
+    /// caches the input and puzzle of every unlocked day of a year
+    Prefetch {
+        #[arg(value_parser = parse_event)]
+        year: helpers::Event,
+    },
+    /// checks the session cookie and shows who it belongs to
+    Whoami,
+}
 
-    // Push it into the config
-    bins.push(bin_table);
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// lists cached pages and how they were fetched
+    List(CacheFilter),
+    /// checks cached pages, failing if any will be fetched again
+    Verify(CacheFilter),
+    /// deletes cached pages
+    Purge(CacheFilter),
+}
 
-    // write the config back to disk
-    fs::write("./cargo.toml", cargo_toml.to_string()).unwrap();
+/// limits a cache command to a single year and/or day
+#[derive(Args)]
+struct CacheFilter {
+    #[arg(value_parser = parse_event)]
+    year: Option<helpers::Event>,
+    #[arg(requires = "year")]
+    day: Option<u8>,
 }
 
-fn main() {
-    let year = std::env::args()
-        .nth(1)
-        .expect("year should be provided as the first argument");
-    let day = std::env::args()
-        .nth(2)
-        .expect("day should be provided as the second argument");
+impl CacheFilter {
+    fn validate(&self) -> (Option<u16>, Option<u8>) {
+        match (self.year, self.day) {
+            (Some(event), Some(day)) => {
+                let day = event.day(day).unwrap_or_else(|error| error.exit());
+                (Some(day.year()), Some(day.number()))
+            }
+            (year, _) => (year.map(|event| event.year()), None),
+        }
+    }
+}
 
-    let repo = Repository::init(".").expect("should always be run in the repository root");
-    if git_repo_has_uncommitted_changes(&repo).unwrap() {
-        panic!("Repository should be clean before generating files")
+/// exits unless the code for a day has been generated
+fn ensure_generated(day: helpers::Day) -> String {
+    let root = helpers::Client::from_env()
+        .unwrap_or_else(|error| error.exit())
+        .root()
+        .to_owned();
+    let main_rs = root
+        .join(day.year().to_string())
+        .join(day.number().to_string())
+        .join("main.rs");
+    if !main_rs.exists() {
+        helpers::AocError::Config(format!(
+            "{day} has not been generated yet, run `aoc new {} {}` first",
+            day.year(),
+            day.number()
+        ))
+        .exit();
     }
+    format!("{}_{}", day.year(), day.number())
+}
+
+/// cargo itself, the same one as `cargo run` if that is how the tools were started
+fn cargo_command() -> process::Command {
+    process::Command::new(std::env::var("CARGO").unwrap_or("cargo".to_owned()))
+}
+
+/// runs cargo with its output shown
+fn cargo_status(args: &[&str]) -> Result<process::ExitStatus, helpers::AocError> {
+    Ok(cargo_command().args(args).status()?)
+}
 
-    // create the parent directories if they don't already exist
-    let path = format!("./{year}/{day}");
-    std::fs::create_dir_all(&path).unwrap();
+/// where cargo builds to, which may be set by CARGO_TARGET_DIR or in
+/// .cargo/config.toml and is relative to the workspace rather than the
+/// current directory otherwise
+fn target_dir() -> Result<PathBuf, helpers::AocError> {
+    let output = cargo_command()
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(helpers::AocError::Config(
+            "cargo metadata failed to find the target directory".to_owned(),
+        ));
+    }
+    serde_json::from_slice::<serde_json::Value>(&output.stdout)
+        .ok()
+        .and_then(|metadata| Some(PathBuf::from(metadata["target_directory"].as_str()?)))
+        .ok_or(helpers::AocError::Config(
+            "cargo metadata did not include the target directory".to_owned(),
+        ))
+}
+
+/// runs cargo and exits with its exit code if it fails
+fn cargo(args: &[&str]) {
+    let status = cargo_status(args).unwrap_or_else(|error| error.exit());
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+fn new_command(day: helpers::Day, force: bool, flavor: &str) {
+    let mut files = scaffold::Rollback::default();
+    if let Err(error) = generate_day(day, force, flavor, &mut files) {
+        if let Err(restore_error) = files.restore() {
+            eprintln!("Failed to roll back the files of {day}: {restore_error}");
+        }
+        error.exit();
+    }
+}
+
+/// writes the code, tests and puzzle of a day and adds it to Cargo.toml,
+/// keeping track of every file in `files` so that they can be rolled back
+fn generate_day(
+    day: helpers::Day,
+    force: bool,
+    flavor: &str,
+    files: &mut scaffold::Rollback,
+) -> Result<(), helpers::AocError> {
+    let (year, day_number) = (day.year(), day.number());
+
+    // days are generated next to Cargo.toml wherever this is run from
+    let manifest = manifest::find_manifest()?;
+    let root = manifest.parent().unwrap_or(Path::new("."));
+    std::env::set_current_dir(root)?;
+
+    let repo = Repository::init(".")?;
+    if git_repo_has_uncommitted_changes(&repo)? {
+        return Err(helpers::AocError::Config(
+            "the repository should be clean before generating files".to_owned(),
+        ));
+    }
 
-    // write code files
+    let path = format!("./{year}/{day_number}");
     let main_rs_path = format!("{path}/main.rs");
-    fs::write(&main_rs_path, get_main_rs_content(&year, &day)).unwrap();
-    fs::write(format!("{path}/tests.rs"), get_test_rs_content(&year, &day)).unwrap();
+    let tests_rs_path = format!("{path}/tests.rs");
+    let existing = [&main_rs_path, &tests_rs_path]
+        .into_iter()
+        .filter(|path| Path::new(path).exists())
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if !existing.is_empty() && !force {
+        return Err(helpers::AocError::Config(format!(
+            "{day} has already been generated, pass --force to overwrite {}",
+            existing.join(" and ")
+        )));
+    }
+
+    // fail before fetching anything if the templates do not exist
+    template::load(flavor, "main.rs")?;
+
+    // the puzzle page is a convenience so do not fail if it cannot be downloaded
+    let page = helpers::get_puzzle_page(year, day_number, false)
+        .inspect_err(|error| eprintln!("Failed to download the puzzle description: {error}"))
+        .ok();
+    let example = Example {
+        title: page.as_deref().and_then(helpers::find_title),
+        input: page.as_deref().and_then(helpers::find_example_input),
+        answers: page
+            .as_deref()
+            .map(helpers::find_example_answers)
+            .unwrap_or_default(),
+    };
+
+    let code = [
+        (
+            PathBuf::from(&main_rs_path),
+            rustfmt(get_main_rs_content(day, &example, flavor)?),
+        ),
+        (
+            PathBuf::from(&tests_rs_path),
+            rustfmt(get_test_rs_content(day, &example, flavor)?),
+        ),
+    ];
+    for (path, content) in &code {
+        if path.exists() {
+            print!(
+                "{}",
+                scaffold::diff(path, &fs::read_to_string(path)?, content)?
+            );
+        }
+    }
+
+    if let Some(page) = &page {
+        files.create_dir_all(Path::new(&path))?;
+        files.track(Path::new(&format!("{path}/puzzle.md")))?;
+        helpers::save_puzzle(root, year, day_number, page)?;
+    }
 
-    update_cargo_toml(Bin {
-        name: format!("{year}_{day}"),
+    let bin = manifest::Bin {
+        name: format!("{year}_{day_number}"),
         path: main_rs_path,
-    });
+    };
+    match write_day(&manifest, &bin, &code, files)? {
+        true => Ok(()),
+        false => Err(helpers::AocError::Config(format!(
+            "the code generated for {day} from the '{flavor}' template does not compile, so it was rolled back"
+        ))),
+    }
+}
+
+/// writes the code of a day and adds it to Cargo.toml, keeping track of every
+/// file in `files`. Returns whether the day compiles, since a template which
+/// does not would otherwise only be noticed later
+fn write_day(
+    manifest: &Path,
+    bin: &manifest::Bin,
+    code: &[(PathBuf, String)],
+    files: &mut scaffold::Rollback,
+) -> Result<bool, helpers::AocError> {
+    for (path, content) in code {
+        if let Some(directory) = path.parent() {
+            files.create_dir_all(directory)?;
+        }
+        files.write(path, content)?;
+    }
+    manifest::update_cargo_toml(manifest, bin, files)?;
+    let manifest = manifest.to_string_lossy();
+    Ok(cargo_status(&["check", "--manifest-path", &manifest, "--bin", &bin.name])?.success())
+}
+
+fn fetch_command(day: helpers::Day, wait: bool) {
+    let client = helpers::Client::from_env()
+        .unwrap_or_else(|error| error.exit())
+        .wait_for_unlock(wait);
+    let input = client
+        .cache_input(day.year(), day.number())
+        .unwrap_or_else(|error| error.exit());
+    let page = helpers::Page::Input {
+        year: day.year(),
+        day: day.number(),
+    };
+    println!(
+        "Cached the input for {day} ({} lines) in {}",
+        input.lines().count(),
+        client.cache_dir().join(page.cache_path()).display()
+    );
+}
+
+fn puzzle_command(day: helpers::Day, wait: bool) {
+    let client = helpers::Client::from_env()
+        .unwrap_or_else(|error| error.exit())
+        .wait_for_unlock(wait);
+    let page = client
+        .refresh_puzzle_page(day.year(), day.number())
+        .unwrap_or_else(|error| error.exit());
+    helpers::save_puzzle(client.root(), day.year(), day.number(), &page)
+        .unwrap_or_else(|error| error.exit());
+    println!(
+        "Saved the puzzle for {day} in {}",
+        client
+            .root()
+            .join(day.year().to_string())
+            .join(day.number().to_string())
+            .join("puzzle.md")
+            .display()
+    );
+}
+
+fn submit_command(day: helpers::Day, part: u8, answer: &str) {
+    let verdict = helpers::submit_answer(day.year(), day.number(), part, answer)
+        .unwrap_or_else(|error| error.exit());
+    println!("{answer} is {verdict}");
+    if verdict != helpers::Verdict::Correct {
+        process::exit(1);
+    }
+}
+
+fn bench_command(day: helpers::Day, runs: u32) {
+    let bin = ensure_generated(day);
+    cargo(&["build", "--release", "--bin", &bin]);
+    let executable = target_dir()
+        .unwrap_or_else(|error| error.exit())
+        .join("release")
+        .join(format!("{bin}{}", std::env::consts::EXE_SUFFIX));
+    let run = || {
+        let start = Instant::now();
+        let output = process::Command::new(&executable)
+            .output()
+            .unwrap_or_else(|error| helpers::AocError::from(error).exit());
+        if !output.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            process::exit(output.status.code().unwrap_or(1));
+        }
+        start.elapsed()
+    };
+    // the first run may have to fetch the input
+    run();
+    let times = (0..runs.max(1)).map(|_| run()).collect::<Vec<_>>();
+    println!(
+        "{bin} over {} runs: min {:?}, mean {:?}, max {:?}",
+        times.len(),
+        times.iter().min().unwrap(),
+        times.iter().sum::<Duration>() / times.len() as u32,
+        times.iter().max().unwrap()
+    );
+}
+
+fn readme_command(event: helpers::Event) {
+    let days = helpers::get_personal_stats(event.year()).unwrap_or_else(|error| error.exit());
+    let stars = days
+        .iter()
+        .flat_map(|day| {
+            (1..=2)
+                .filter(|part| day.parts[usize::from(*part) - 1].is_some())
+                .map(|part| (day.day, part))
+        })
+        .collect::<Vec<_>>();
+    // the README is next to Cargo.toml wherever this is run from
+    let path = manifest::find_manifest()
+        .unwrap_or_else(|error| error.exit())
+        .with_file_name("README.md");
+    let readme =
+        fs::read_to_string(&path).unwrap_or_else(|error| helpers::AocError::from(error).exit());
+    fs::write(&path, readme::update_readme(&readme, event, &stars))
+        .unwrap_or_else(|error| helpers::AocError::from(error).exit());
+    println!("Marked {} stars of {event} in README.md", stars.len());
+}
+
+fn cache_command(command: CacheCommand) {
+    let client = helpers::Client::from_env().unwrap_or_else(|error| error.exit());
+    match command {
+        CacheCommand::List(filter) => {
+            let (year, day) = filter.validate();
+            for entry in client
+                .cache_entries(year, day)
+                .unwrap_or_else(|error| error.exit())
+            {
+                match entry.metadata {
+                    Some(metadata) => println!(
+                        "{}\t{} bytes\tfetched {}\tHTTP {}\tsession {}",
+                        entry.path.display(),
+                        metadata.length,
+                        metadata.fetched.format("%Y-%m-%d %H:%M:%S UTC"),
+                        metadata.status,
+                        metadata.session
+                    ),
+                    None => println!("{}\t(no metadata)", entry.path.display()),
+                }
+            }
+        }
+        CacheCommand::Verify(filter) => {
+            let (year, day) = filter.validate();
+            let mut untrusted = 0;
+            for entry in client
+                .cache_entries(year, day)
+                .unwrap_or_else(|error| error.exit())
+            {
+                let problems = client.verify_cache_entry(&entry.page);
+                if !problems.iter().all(helpers::CacheProblem::is_trusted) {
+                    untrusted += 1;
+                }
+                let problems = problems.iter().map(ToString::to_string).collect::<Vec<_>>();
+                match problems.is_empty() {
+                    true => println!("{}\tok", entry.path.display()),
+                    false => println!("{}\t{}", entry.path.display(), problems.join(", ")),
+                }
+            }
+            if untrusted > 0 {
+                eprintln!("{untrusted} cached pages will be fetched again when next used");
+                process::exit(1);
+            }
+        }
+        CacheCommand::Purge(filter) => {
+            let (year, day) = filter.validate();
+            let purged = client
+                .purge_cache(year, day)
+                .unwrap_or_else(|error| error.exit());
+            println!("Purged {purged} cached pages");
+        }
+    }
+}
+
+fn leaderboard_command(event: helpers::Event, id: u64) {
+    let leaderboard =
+        helpers::get_leaderboard(event.year(), id).unwrap_or_else(|error| error.exit());
+    print!("{}", helpers::render_leaderboard(event, &leaderboard));
+}
+
+fn stats_command(event: helpers::Event) {
+    let days = helpers::get_personal_stats(event.year()).unwrap_or_else(|error| error.exit());
+    print!("{}", helpers::render_personal_stats(event.year(), &days));
+}
+
+fn prefetch_command(event: helpers::Event) {
+    let client = helpers::Client::from_env().unwrap_or_else(|error| error.exit());
+    let mut fetched = 0;
+    let mut skipped = 0;
+    for day in event.all_days() {
+        let prefetched = client
+            .prefetch_day(day.year(), day.number())
+            .unwrap_or_else(|error| error.exit());
+        println!("Day {}: {prefetched}", day.number());
+        match prefetched {
+            helpers::Prefetched::Fetched => fetched += 1,
+            helpers::Prefetched::Cached => {}
+            _ => skipped += 1,
+        }
+    }
+    println!("Fetched {fetched} days of {event}, {skipped} could not be fetched");
+}
+
+fn whoami_command() {
+    let account = helpers::whoami().unwrap_or_else(|error| error.exit());
+    match account.supporter {
+        true => println!("Logged in as {} (AoC++)", account.name),
+        false => println!("Logged in as {}", account.name),
+    }
+    let now = chrono::Utc::now();
+    match (account.session_expires, account.days_until_expiry(now)) {
+        (Some(expires), Some(days)) if expires > now => println!(
+            "The session cookie expires in {days} days, on {}",
+            expires.format("%Y-%m-%d")
+        ),
+        (Some(expires), _) => println!(
+            "The session cookie should have expired on {} but AoC still accepts it",
+            expires.format("%Y-%m-%d")
+        ),
+        _ => println!(
+            "Set SESSION_EXPIRES in .env to the cookie's expiry date shown by the browser to see how long it is valid"
+        ),
+    }
+}
+
+/// refuses to continue while inputs or the session cookie could be committed,
+/// after offering to add the rules which ignore them
+fn guard_against_leaks(repo: &Repository) -> Result<(), helpers::AocError> {
+    let client = helpers::Client::from_env()?;
+    let mut leaks = client.find_leaks(repo)?;
+    let rules = helpers::ignore_rules(&leaks);
+    if !rules.is_empty() && io::stdin().is_terminal() {
+        eprintln!("{}", helpers::AocError::Leaking(leaks.clone()));
+        eprint!("Add {} to .gitignore? [y/N] ", rules.join(" and "));
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if answer.trim().eq_ignore_ascii_case("y") {
+            helpers::add_ignore_rules(repo, &rules)?;
+            leaks = client.find_leaks(repo)?;
+        }
+    }
+    match leaks.is_empty() {
+        true => Ok(()),
+        false => Err(helpers::AocError::Leaking(leaks)),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Ok(repo) = Repository::discover(".") {
+        guard_against_leaks(&repo).unwrap_or_else(|error| error.exit());
+    }
+    match cli.command {
+        Command::New {
+            day,
+            force,
+            template,
+        } => new_command(day.validate(), force, &template),
+        Command::Fetch { day, wait } => fetch_command(day.validate(), wait),
+        Command::Puzzle { day, wait } => puzzle_command(day.validate(), wait),
+        Command::Run { day, release, args } => {
+            let bin = ensure_generated(day.validate());
+            let mut cargo_args = vec!["run", "--bin", &bin];
+            if release {
+                cargo_args.push("--release");
+            }
+            cargo_args.push("--");
+            cargo_args.extend(args.iter().map(String::as_str));
+            cargo(&cargo_args);
+        }
+        Command::Test { day, args } => {
+            let bin = ensure_generated(day.validate());
+            let mut cargo_args = vec!["test", "--bin", &bin, "--"];
+            cargo_args.extend(args.iter().map(String::as_str));
+            cargo(&cargo_args);
+        }
+        Command::Submit { day, part, answer } => submit_command(day.validate(), part, &answer),
+        Command::Bench { day, runs } => bench_command(day.validate(), runs),
+        Command::Readme { year } => readme_command(year),
+        Command::Cache { command } => cache_command(command),
+        Command::Leaderboard { year, id } => leaderboard_command(year, id),
+        Command::Stats { year } => stats_command(year),
+        Command::Prefetch { year } => prefetch_command(year),
+        Command::Whoami => whoami_command(),
+    }
 }
@@ -0,0 +1,88 @@
+use crate::helpers::AocError;
+use crate::scaffold::Rollback;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, value};
+
+/// a `[[bin]]` target in Cargo.toml
+pub struct Bin {
+    pub name: String,
+    /// relative to the directory of Cargo.toml (Ex: ./2025/5/main.rs)
+    pub path: String,
+}
+
+/// the closest Cargo.toml in `directory` or its parents
+pub fn search_manifest(directory: &Path) -> Option<PathBuf> {
+    directory
+        .ancestors()
+        .map(|directory| directory.join("Cargo.toml"))
+        .find(|manifest| manifest.is_file())
+}
+
+/// the Cargo.toml of the repository, which cargo points to when the tools are
+/// started with `cargo run`
+pub fn find_manifest() -> Result<PathBuf, AocError> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(|directory| PathBuf::from(directory).join("Cargo.toml"))
+        .filter(|manifest| manifest.is_file())
+        .or_else(|| search_manifest(&std::env::current_dir().ok()?))
+        .ok_or(AocError::Config(
+            "Cargo.toml could not be found in the current directory or its parents".to_owned(),
+        ))
+}
+
+/// days are ordered by year and then day, after every other binary (Ex: aoc)
+fn bin_order(table: &Table) -> Option<(u16, u8)> {
+    let (year, day) = table.get("name")?.as_str()?.split_once('_')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// adds a `[[bin]]` table to a manifest, or updates the path of the one with
+/// the same name, keeping the days in order
+pub fn add_bin(manifest: &str, bin: &Bin) -> Result<String, AocError> {
+    let mut document = manifest
+        .parse::<DocumentMut>()
+        .map_err(|error| AocError::Config(format!("Cargo.toml could not be parsed ({error})")))?;
+    let bins = document
+        .entry("bin")
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+        .ok_or(AocError::Config(
+            "bin in Cargo.toml should be written as [[bin]] tables".to_owned(),
+        ))?;
+
+    let mut tables = bins.iter().cloned().collect::<Vec<_>>();
+    let existing = tables
+        .iter_mut()
+        .find(|table| table.get("name").and_then(Item::as_str) == Some(bin.name.as_str()));
+    match existing {
+        Some(table) => table["path"] = value(&bin.path),
+        None => {
+            let mut table = Table::new();
+            table["name"] = value(&bin.name);
+            table["path"] = value(&bin.path);
+            tables.push(table);
+        }
+    }
+    tables.sort_by_key(bin_order);
+
+    // tables are written in the order of their position in the file, so keep
+    // every [[bin]] together where the first one was
+    let position = tables.iter().filter_map(Table::position).min();
+    bins.clear();
+    for mut table in tables {
+        table.set_position(position);
+        bins.push(table);
+    }
+    Ok(document.to_string())
+}
+
+/// adds a day to Cargo.toml, which is left alone if it is already there
+pub fn update_cargo_toml(manifest: &Path, bin: &Bin, files: &mut Rollback) -> Result<(), AocError> {
+    let original = fs::read_to_string(manifest)?;
+    let updated = add_bin(&original, bin)?;
+    if updated != original {
+        files.write(manifest, &updated)?;
+    }
+    Ok(())
+}
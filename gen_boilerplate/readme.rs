@@ -0,0 +1,115 @@
+use crate::helpers::Event;
+use std::collections::HashMap;
+
+const HEADERS: [&str; 3] = ["Day-Part", "Complete", "Notes"];
+
+/// the number of columns a cell takes up in an editor, where the star is as
+/// wide as two other characters
+fn width(cell: &str) -> usize {
+    cell.chars()
+        .map(|character| match character {
+            '⭐' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// the `## {year}` table at the top of the README with a star for every part
+/// in `stars` (as day and part). Notes which are already in the table are kept
+fn star_table(event: Event, stars: &[(u8, u8)], notes: &HashMap<String, String>) -> Vec<String> {
+    let rows = event
+        .all_days()
+        .flat_map(|day| (1..=2).map(move |part| (day.number(), part)))
+        .map(|(day, part)| {
+            let name = format!("{day}-{part}");
+            let complete = match stars.contains(&(day, part)) {
+                true => "⭐".to_owned(),
+                false => String::new(),
+            };
+            let note = notes.get(&name).cloned().unwrap_or_default();
+            [name, complete, note]
+        })
+        .collect::<Vec<_>>();
+    let widths = (0..HEADERS.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| width(&row[column]))
+                .chain([HEADERS[column].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let format_row = |cells: &[String]| {
+        let cells = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, column_width)| format!("{cell}{}", " ".repeat(column_width - width(cell))))
+            .collect::<Vec<_>>();
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut table = vec![
+        format_row(&HEADERS.map(str::to_owned)),
+        format_row(
+            &widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>(),
+        ),
+    ];
+    table.extend(rows.iter().map(|row| format_row(row)));
+    table
+}
+
+/// marks the stars collected in an event in the README, adding a section for
+/// the event before the first other section if there isn't one yet
+pub fn update_readme(readme: &str, event: Event, stars: &[(u8, u8)]) -> String {
+    let mut lines = readme.lines().map(str::to_owned).collect::<Vec<_>>();
+    let heading = format!("## {event}");
+    let Some(section) = lines.iter().position(|line| line.trim() == heading) else {
+        let table = star_table(event, stars, &HashMap::new());
+        let position = lines
+            .iter()
+            .position(|line| line.starts_with("## "))
+            .unwrap_or(lines.len());
+        let mut section = vec![heading, String::new()];
+        section.extend(table);
+        section.push(String::new());
+        lines.splice(position..position, section);
+        return lines.join("\n") + "\n";
+    };
+
+    // the table is the first run of lines starting with | in the section
+    let start = lines[section + 1..]
+        .iter()
+        .take_while(|line| !line.starts_with("## "))
+        .position(|line| line.starts_with('|'))
+        .map(|offset| section + 1 + offset);
+    let (start, end) = match start {
+        Some(start) => (
+            start,
+            start
+                + lines[start..]
+                    .iter()
+                    .take_while(|line| line.starts_with('|'))
+                    .count(),
+        ),
+        None => (section + 1, section + 1),
+    };
+    let notes = lines[start..end]
+        .iter()
+        .filter_map(|line| {
+            let cells = line.trim_matches('|').split('|').collect::<Vec<_>>();
+            match cells.as_slice() {
+                [name, _, note] => Some((name.trim().to_owned(), note.trim().to_owned())),
+                _ => None,
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    let mut table = star_table(event, stars, &notes);
+    if start == end {
+        // keep a blank line between the heading and the new table
+        table.insert(0, String::new());
+    }
+    lines.splice(start..end, table);
+    lines.join("\n") + "\n"
+}
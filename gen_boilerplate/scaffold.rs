@@ -0,0 +1,81 @@
+use crate::helpers::AocError;
+use git2::Patch;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// every file and directory written while generating a day, so that a day is
+/// never left half generated
+#[derive(Default)]
+pub struct Rollback {
+    /// what each file contained before, or `None` if it did not exist
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+    directories: Vec<PathBuf>,
+}
+
+impl Rollback {
+    /// remembers what a file contains before something else writes to it
+    pub fn track(&mut self, path: &Path) -> Result<(), AocError> {
+        if self.files.iter().any(|(tracked, _)| tracked == path) {
+            return Ok(());
+        }
+        let original = match fs::read(path) {
+            Ok(original) => Some(original),
+            Err(error) if error.kind() == ErrorKind::NotFound => None,
+            Err(error) => return Err(error.into()),
+        };
+        self.files.push((path.to_owned(), original));
+        Ok(())
+    }
+
+    pub fn write(&mut self, path: &Path, contents: &str) -> Result<(), AocError> {
+        self.track(path)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// creates a directory and any missing parents
+    pub fn create_dir_all(&mut self, path: &Path) -> Result<(), AocError> {
+        let missing = path
+            .ancestors()
+            .take_while(|directory| !directory.as_os_str().is_empty() && !directory.exists())
+            .map(Path::to_owned)
+            .collect::<Vec<_>>();
+        fs::create_dir_all(path)?;
+        // parents first so that they are removed last
+        self.directories.extend(missing.into_iter().rev());
+        Ok(())
+    }
+
+    /// puts every file back the way it was and removes the directories which
+    /// were created, carrying on past errors so that as much as possible is
+    /// restored
+    pub fn restore(self) -> Result<(), AocError> {
+        let mut result = Ok(());
+        for (path, original) in self.files.into_iter().rev() {
+            let restored = match original {
+                Some(original) => fs::write(&path, original),
+                None => fs::remove_file(&path).or_else(|error| match error.kind() {
+                    ErrorKind::NotFound => Ok(()),
+                    _ => Err(error),
+                }),
+            };
+            if let Err(error) = restored {
+                result = result.and(Err(error.into()));
+            }
+        }
+        for directory in self.directories.into_iter().rev() {
+            // anything else in the directory was not written here so keep it
+            let _ = fs::remove_dir(directory);
+        }
+        result
+    }
+}
+
+/// a unified diff of how a file would change (Ex: --- a/2025/5/main.rs)
+pub fn diff(path: &Path, old: &str, new: &str) -> Result<String, AocError> {
+    let path = path.strip_prefix("./").unwrap_or(path);
+    let mut patch =
+        Patch::from_buffers(old.as_bytes(), Some(path), new.as_bytes(), Some(path), None)?;
+    Ok(String::from_utf8_lossy(&patch.to_buf()?).into_owned())
+}
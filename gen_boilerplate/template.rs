@@ -0,0 +1,126 @@
+use crate::helpers::AocError;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// where templates are looked for, relative to the root of the repository
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// the flavors which are always available, even without a templates directory
+const BUILT_IN: [(&str, &str, &str); 5] = [
+    (
+        "plain",
+        "main.rs",
+        include_str!("../templates/plain/main.rs"),
+    ),
+    ("grid", "main.rs", include_str!("../templates/grid/main.rs")),
+    (
+        "graph",
+        "main.rs",
+        include_str!("../templates/graph/main.rs"),
+    ),
+    ("ilp", "main.rs", include_str!("../templates/ilp/main.rs")),
+    ("", "tests.rs", include_str!("../templates/tests.rs")),
+];
+
+/// the template for a file of a day (Ex: main.rs). A flavor's directory
+/// (Ex: templates/grid/tests.rs) is checked first, then the templates shared
+/// by every flavor (Ex: templates/tests.rs), and then the built in copies of both
+pub fn load(flavor: &str, file: &str) -> Result<String, AocError> {
+    let directory = Path::new(TEMPLATES_DIR);
+    for path in [directory.join(flavor).join(file), directory.join(file)] {
+        if path.is_file() {
+            return Ok(fs::read_to_string(path)?);
+        }
+    }
+    BUILT_IN
+        .iter()
+        .find(|(built_in, name, _)| *name == file && [flavor, ""].contains(built_in))
+        .map(|(_, _, template)| template.to_string())
+        .ok_or_else(|| {
+            let flavors = BUILT_IN
+                .iter()
+                .map(|(flavor, _, _)| *flavor)
+                .filter(|flavor| !flavor.is_empty())
+                .collect::<Vec<_>>();
+            AocError::Config(format!(
+                "there is no {file} template for '{flavor}', the built in templates are {} or add one to {TEMPLATES_DIR}/{flavor}/{file}",
+                flavors.join(", ")
+            ))
+        })
+}
+
+/// whether a line is nothing but a section tag (Ex: {{#part2}}), which is
+/// removed along with the line
+fn is_standalone_tag(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with("{{")
+        && line.ends_with("}}")
+        && line.matches("{{").count() == 1
+        && ["{{#", "{{^", "{{/"]
+            .iter()
+            .any(|tag| line.starts_with(tag))
+}
+
+/// fills in a template. `{{name}}` is replaced by a value (or nothing if it is
+/// not known), `{{#name}}...{{/name}}` is only kept when the value is known and
+/// `{{^name}}...{{/name}}` only when it isn't
+pub fn render(template: &str, values: &HashMap<&str, Option<String>>) -> Result<String, AocError> {
+    let template = template
+        .split_inclusive('\n')
+        .map(|line| match is_standalone_tag(line) {
+            true => line.trim(),
+            false => line,
+        })
+        .collect::<String>();
+    render_section(&template, values)
+}
+
+fn render_section(
+    template: &str,
+    values: &HashMap<&str, Option<String>>,
+) -> Result<String, AocError> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let tag = &rest[start + 2..];
+        let end = tag.find("}}").ok_or(AocError::Config(format!(
+            "a placeholder in a template is not closed: '{}'",
+            tag.lines().next().unwrap_or_default()
+        )))?;
+        let (name, after) = (&tag[..end], &tag[end + 2..]);
+        rest = match name.split_at_checked(1) {
+            Some((kind @ ("#" | "^"), name)) => {
+                let close = format!("{{{{/{name}}}}}");
+                let inner_end = after.find(&close).ok_or(AocError::Config(format!(
+                    "the section {{{{{kind}{name}}}}} in a template is not closed with {close}"
+                )))?;
+                let value = values.get(name).ok_or(AocError::Config(format!(
+                    "{{{{{kind}{name}}}}} is not a placeholder, the placeholders are {}",
+                    placeholder_names(values)
+                )))?;
+                if value.is_some() == (kind == "#") {
+                    rendered.push_str(&render_section(&after[..inner_end], values)?);
+                }
+                &after[inner_end + close.len()..]
+            }
+            _ => {
+                let value = values.get(name.trim()).ok_or(AocError::Config(format!(
+                    "{{{{{name}}}}} is not a placeholder, the placeholders are {}",
+                    placeholder_names(values)
+                )))?;
+                rendered.push_str(value.as_deref().unwrap_or_default());
+                after
+            }
+        };
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+fn placeholder_names(values: &HashMap<&str, Option<String>>) -> String {
+    let mut names = values.keys().copied().collect::<Vec<_>>();
+    names.sort();
+    names.join(", ")
+}
@@ -0,0 +1,497 @@
+use super::*;
+
+fn day(year: u16, day: u8) -> helpers::Day {
+    helpers::Event::new(year).unwrap().day(day).unwrap()
+}
+
+#[test]
+fn single_line_example_literal() {
+    assert_eq!(example_input_literal("11-22,95-115\n"), r#""11-22,95-115""#);
+    assert_eq!(example_input_literal(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+}
+
+#[test]
+fn multi_line_example_literal() {
+    assert_eq!(
+        example_input_literal("L68\nL30\n"),
+        "r#\"\n    L68\n    L30\n\"#"
+    );
+}
+
+#[test]
+fn whitespace_sensitive_example_literal() {
+    assert_eq!(
+        example_input_literal("123 328\n 45 64 \n*   + \n"),
+        "r#\"\n123 328\n 45 64 \n*   + \n\"#"
+    );
+}
+
+#[test]
+fn fully_indented_example_literal() {
+    assert_eq!(example_input_literal("  a\n  b\n"), r#""  a\n  b""#);
+}
+
+#[test]
+fn example_literal_with_raw_string_terminator() {
+    assert_eq!(
+        example_input_literal("\"#\nabc\n"),
+        "r##\"\n    \"#\n    abc\n\"##"
+    );
+}
+
+#[test]
+fn answer_types() {
+    assert_eq!(answer_type(None), "u64");
+    assert_eq!(answer_type(Some("3121910778619")), "u64");
+    assert_eq!(answer_type(Some("-12")), "i64");
+    assert_eq!(answer_type(Some("6,1,3")), "String");
+}
+
+#[test]
+fn test_rs_with_example() {
+    let example = Example {
+        title: None,
+        input: Some("L68\nL30\n".to_owned()),
+        answers: vec![Some("3".to_owned()), Some("ABC".to_owned())],
+    };
+    assert_eq!(
+        get_test_rs_content(day(2025, 1), &example, "plain").unwrap(),
+        r##"// https://adventofcode.com/2025/day/1
+
+use super::*;
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1(&Input::example(EXAMPLE_INPUT)), 3);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2(&Input::example(EXAMPLE_INPUT)), "ABC");
+}
+"##
+    );
+}
+
+#[test]
+fn main_rs_shares_example() {
+    let example = Example {
+        title: None,
+        input: Some("L68\nL30\n".to_owned()),
+        answers: vec![Some("3".to_owned())],
+    };
+    let content = get_main_rs_content(day(2025, 1), &example, "plain").unwrap();
+    assert!(content.contains("static EXAMPLE_INPUT: &str = r#\"\n    L68\n    L30\n\"#;"));
+    assert!(content.contains("helpers::get_input_from_args(2025, 1, EXAMPLE_INPUT)"));
+}
+
+#[test]
+fn test_rs_without_part2() {
+    let example = Example {
+        title: None,
+        input: Some("L68\nL30\n".to_owned()),
+        answers: vec![Some("3".to_owned())],
+    };
+    let content = get_test_rs_content(day(2025, 1), &example, "plain").unwrap();
+    assert!(content.contains("fn part1_example()"));
+    assert!(!content.contains("fn part2_example()"));
+    assert!(content.contains("// the example answer for part 2 could not be found"));
+}
+
+#[test]
+fn test_rs_without_answers() {
+    let example = Example {
+        title: None,
+        input: None,
+        answers: Vec::new(),
+    };
+    assert_eq!(
+        get_test_rs_content(day(2025, 1), &example, "plain").unwrap(),
+        r##"// https://adventofcode.com/2025/day/1
+
+// the example answer for part 1 could not be found, add a test once it is known
+
+// the example answer for part 2 could not be found, add a test once it is known
+"##
+    );
+}
+
+#[test]
+fn last_day_has_one_part() {
+    let example = Example {
+        title: None,
+        input: Some("#####\n".to_owned()),
+        answers: vec![Some("3".to_owned())],
+    };
+    let main_rs = get_main_rs_content(day(2024, 25), &example, "plain").unwrap();
+    assert!(main_rs.contains("fn part1("));
+    assert!(!main_rs.contains("part2"));
+    let test_rs = get_test_rs_content(day(2025, 12), &example, "plain").unwrap();
+    assert!(test_rs.contains("fn part1_example()"));
+    assert!(!test_rs.contains("part2"));
+}
+
+#[test]
+fn cli_validates_days() {
+    let cli = Cli::try_parse_from(["aoc", "new", "2025", "5"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Command::New {
+            day: DayArg { day: 5, .. },
+            force: false,
+            ..
+        }
+    ));
+    assert!(Cli::try_parse_from(["aoc", "new", "2014", "5"]).is_err());
+    assert!(Cli::try_parse_from(["aoc", "new", "2025"]).is_err());
+    assert!(Cli::try_parse_from(["aoc", "fetch", "twenty", "5"]).is_err());
+    assert!(Cli::try_parse_from(["aoc", "submit", "2025", "5", "3", "42"]).is_err());
+    assert!(Cli::try_parse_from(["aoc", "cache", "list", "2025", "5"]).is_ok());
+    assert!(Cli::try_parse_from(["aoc"]).is_err());
+}
+
+#[test]
+fn cli_passes_arguments_through() {
+    let cli = Cli::try_parse_from(["aoc", "run", "2025", "5", "--", "--example"]).unwrap();
+    let Command::Run { args, release, .. } = cli.command else {
+        panic!("expected the run command");
+    };
+    assert_eq!(args, ["--example"]);
+    assert!(!release);
+}
+
+const README: &str = "# Advent of Code
+
+## 2015
+
+| Day-Part | Complete | Notes                |
+| -------- | -------- | -------------------- |
+| 1-1      | ⭐       | counting parentheses |
+| 1-2      |          |                      |
+
+## Setup
+";
+
+#[test]
+fn readme_round_trips() {
+    let readme = include_str!("../README.md");
+    let stars = readme
+        .lines()
+        .filter(|line| line.contains('⭐'))
+        .filter_map(|line| {
+            let (day, part) = line
+                .trim_start_matches("| ")
+                .split_once(' ')?
+                .0
+                .split_once('-')?;
+            Some((day.parse().ok()?, part.parse().ok()?))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        readme::update_readme(readme, helpers::Event::new(2025).unwrap(), &stars),
+        readme
+    );
+}
+
+#[test]
+fn readme_marks_new_stars() {
+    let updated = readme::update_readme(
+        README,
+        helpers::Event::new(2015).unwrap(),
+        &[(1, 1), (1, 2)],
+    );
+    assert!(updated.contains("| 1-1      | ⭐       | counting parentheses |\n| 1-2      | ⭐       |                      |\n"));
+}
+
+#[test]
+fn readme_adds_missing_event() {
+    let updated = readme::update_readme(README, helpers::Event::new(2025).unwrap(), &[(3, 2)]);
+    assert!(updated.starts_with(
+        "# Advent of Code\n\n## 2025\n\n| Day-Part | Complete | Notes |\n| -------- | -------- | ----- |\n| 1-1      |          |       |\n"
+    ));
+    assert!(updated.contains("| 3-2      | ⭐       |       |\n"));
+    assert!(updated.contains("| 12-2     |          |       |\n\n## 2015\n"));
+}
+
+fn bin(year: u16, day: u8) -> manifest::Bin {
+    manifest::Bin {
+        name: format!("{year}_{day}"),
+        path: format!("./{year}/{day}/main.rs"),
+    }
+}
+
+#[test]
+fn manifest_bins_are_sorted() {
+    let manifest = include_str!("../tests/fixtures/manifests/unsorted.toml");
+    assert_eq!(
+        manifest::add_bin(manifest, &bin(2025, 3)).unwrap(),
+        include_str!("../tests/fixtures/manifests/sorted.toml")
+    );
+}
+
+#[test]
+fn manifest_bins_are_added_once() {
+    let manifest = include_str!("../tests/fixtures/manifests/sorted.toml");
+    assert_eq!(
+        manifest::add_bin(manifest, &bin(2025, 3)).unwrap(),
+        manifest
+    );
+
+    let moved = manifest::Bin {
+        name: "2025_3".to_owned(),
+        path: "./2025/3/solution.rs".to_owned(),
+    };
+    let updated = manifest::add_bin(manifest, &moved).unwrap();
+    assert_eq!(updated.matches("2025_3").count(), 1);
+    assert!(updated.contains("name = \"2025_3\"\npath = \"./2025/3/solution.rs\"\n"));
+}
+
+#[test]
+fn manifest_without_bins() {
+    assert_eq!(
+        manifest::add_bin("[package]\nname = \"advent_of_code\"\n", &bin(2025, 1)).unwrap(),
+        "[package]\nname = \"advent_of_code\"\n\n[[bin]]\nname = \"2025_1\"\npath = \"./2025/1/main.rs\"\n"
+    );
+    assert!(manifest::add_bin("bin = 1\n", &bin(2025, 1)).is_err());
+}
+
+#[test]
+fn manifest_is_found_in_parents() {
+    let root = tempfile::tempdir().unwrap();
+    let day = root.path().join("2025/5");
+    fs::create_dir_all(&day).unwrap();
+    assert_eq!(manifest::search_manifest(&day), None);
+
+    fs::write(root.path().join("Cargo.toml"), "[package]\n").unwrap();
+    assert_eq!(
+        manifest::search_manifest(&day),
+        Some(root.path().join("Cargo.toml"))
+    );
+}
+
+#[test]
+fn manifest_is_updated_in_place() {
+    let root = tempfile::tempdir().unwrap();
+    let path = root.path().join("Cargo.toml");
+    fs::write(
+        &path,
+        include_str!("../tests/fixtures/manifests/unsorted.toml"),
+    )
+    .unwrap();
+    let mut files = scaffold::Rollback::default();
+    manifest::update_cargo_toml(&path, &bin(2025, 3), &mut files).unwrap();
+    manifest::update_cargo_toml(&path, &bin(2025, 3), &mut files).unwrap();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        include_str!("../tests/fixtures/manifests/sorted.toml")
+    );
+}
+
+#[test]
+fn cli_forces_overwrites() {
+    let cli = Cli::try_parse_from(["aoc", "new", "2025", "5", "--force"]).unwrap();
+    assert!(matches!(cli.command, Command::New { force: true, .. }));
+}
+
+#[test]
+fn cli_selects_template() {
+    let cli = Cli::try_parse_from(["aoc", "new", "2025", "5"]).unwrap();
+    assert!(matches!(cli.command, Command::New { template, .. } if template == "plain"));
+    let cli = Cli::try_parse_from(["aoc", "new", "2025", "11", "--template", "grid"]).unwrap();
+    assert!(matches!(cli.command, Command::New { template, .. } if template == "grid"));
+}
+
+#[test]
+fn rollback_restores_files() {
+    let root = tempfile::tempdir().unwrap();
+    let existing = root.path().join("Cargo.toml");
+    fs::write(&existing, "[package]\n").unwrap();
+    let day = root.path().join("2025/5");
+
+    let mut files = scaffold::Rollback::default();
+    files.create_dir_all(&day).unwrap();
+    files.write(&day.join("main.rs"), "fn main() {}\n").unwrap();
+    files.write(&existing, "[[bin]]\n").unwrap();
+    files.write(&existing, "[[bin]]\n[[bin]]\n").unwrap();
+    // written by something else
+    files.track(&day.join("puzzle.md")).unwrap();
+    fs::write(day.join("puzzle.md"), "# Day 5").unwrap();
+    files.restore().unwrap();
+
+    assert_eq!(fs::read_to_string(&existing).unwrap(), "[package]\n");
+    assert!(!root.path().join("2025").exists());
+}
+
+#[test]
+fn rollback_keeps_other_files() {
+    let root = tempfile::tempdir().unwrap();
+    let day = root.path().join("2025/5");
+
+    let mut files = scaffold::Rollback::default();
+    files.create_dir_all(&day).unwrap();
+    files.write(&day.join("main.rs"), "fn main() {}\n").unwrap();
+    fs::write(root.path().join("2025/notes.md"), "").unwrap();
+    files.restore().unwrap();
+
+    assert!(!day.exists());
+    assert!(root.path().join("2025/notes.md").exists());
+}
+
+#[test]
+fn rollback_after_manifest_fails() {
+    let root = tempfile::tempdir().unwrap();
+    let manifest = root.path().join("Cargo.toml");
+    fs::write(&manifest, "bin = 1\n").unwrap();
+    let main_rs = root.path().join("main.rs");
+
+    let mut files = scaffold::Rollback::default();
+    files.write(&main_rs, "fn main() {}\n").unwrap();
+    assert!(manifest::update_cargo_toml(&manifest, &bin(2025, 5), &mut files).is_err());
+    files.restore().unwrap();
+
+    assert!(!main_rs.exists());
+    assert_eq!(fs::read_to_string(&manifest).unwrap(), "bin = 1\n");
+}
+
+#[test]
+fn rollback_when_the_day_does_not_compile() {
+    let root = tempfile::tempdir().unwrap();
+    let manifest = root.path().join("Cargo.toml");
+    let package = "[package]\nname = \"days\"\nversion = \"0.1.0\"\nedition = \"2024\"\n";
+    fs::write(&manifest, package).unwrap();
+    let directory = root.path().join("2025/5");
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("main.rs"), "fn main() {}\n").unwrap();
+    fs::write(directory.join("tests.rs"), "// tests\n").unwrap();
+
+    let example = Example {
+        title: None,
+        input: None,
+        answers: Vec::new(),
+    };
+    let broken = "fn main() {\n    let day: u8 = \"{{day}}\";\n}\n";
+    let code = [
+        (
+            directory.join("main.rs"),
+            template::render(broken, &placeholders(day(2025, 5), &example)).unwrap(),
+        ),
+        (directory.join("tests.rs"), "// more tests\n".to_owned()),
+    ];
+    // cargo reports the error along with the output of the test
+    let mut files = scaffold::Rollback::default();
+    assert!(!write_day(&manifest, &bin(2025, 5), &code, &mut files).unwrap());
+    files.restore().unwrap();
+
+    assert_eq!(fs::read_to_string(&manifest).unwrap(), package);
+    assert_eq!(
+        fs::read_to_string(directory.join("main.rs")).unwrap(),
+        "fn main() {}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(directory.join("tests.rs")).unwrap(),
+        "// tests\n"
+    );
+}
+
+#[test]
+fn overwrite_diff() {
+    let diff = scaffold::diff(
+        Path::new("./2025/5/main.rs"),
+        "fn main() {\n    part1();\n}\n",
+        "fn main() {\n    part2();\n}\n",
+    )
+    .unwrap();
+    assert_eq!(
+        diff,
+        "diff --git a/2025/5/main.rs b/2025/5/main.rs\nindex 0bf1920..fec6eaf 100644\n--- a/2025/5/main.rs\n+++ b/2025/5/main.rs\n@@ -1,3 +1,3 @@\n fn main() {\n-    part1();\n+    part2();\n }\n"
+    );
+}
+
+fn render(
+    template: &str,
+    values: &[(&'static str, Option<&str>)],
+) -> Result<String, helpers::AocError> {
+    let values = values
+        .iter()
+        .map(|(name, value)| (*name, value.map(str::to_owned)))
+        .collect();
+    template::render(template, &values)
+}
+
+#[test]
+fn template_placeholders() {
+    assert_eq!(
+        render(
+            "{{year}}_{{ day }}",
+            &[("year", Some("2025")), ("day", Some("5"))]
+        )
+        .unwrap(),
+        "2025_5"
+    );
+    assert_eq!(render("[{{title}}]", &[("title", None)]).unwrap(), "[]");
+    assert!(render("{{titel}}", &[("title", None)]).is_err());
+    assert!(render("{{title", &[("title", None)]).is_err());
+}
+
+#[test]
+fn template_sections() {
+    let template = "a\n{{#part2}}\n  b {{part2}}\n{{/part2}}\n{{^part2}}\nc\n{{/part2}}\nd{{#part2}}!{{/part2}}\n";
+    assert_eq!(
+        render(template, &[("part2", Some("2"))]).unwrap(),
+        "a\n  b 2\nd!\n"
+    );
+    assert_eq!(render(template, &[("part2", None)]).unwrap(), "a\nc\nd\n");
+    assert!(render("{{#part2}}", &[("part2", None)]).is_err());
+    assert!(render("{{#part3}}{{/part3}}", &[("part2", None)]).is_err());
+}
+
+#[test]
+fn built_in_templates() {
+    let example = Example {
+        title: Some("Day 5: Cafeteria".to_owned()),
+        input: Some("L68\nL30\n".to_owned()),
+        answers: vec![Some("3".to_owned()), Some("ABC".to_owned())],
+    };
+    for flavor in ["plain", "grid", "graph", "ilp"] {
+        let main_rs = get_main_rs_content(day(2025, 5), &example, flavor).unwrap();
+        assert!(!main_rs.contains("{{"), "{flavor}");
+        assert!(
+            main_rs.starts_with("// https://adventofcode.com/2025/day/5\n// Day 5: Cafeteria\n\n")
+        );
+        assert!(main_rs.contains("fn part2(input: &Input) -> String {") || flavor == "ilp");
+        assert!(main_rs.contains("println!(\"Part 2: {}\", part2(&input));"));
+        let test_rs = get_test_rs_content(day(2025, 5), &example, flavor).unwrap();
+        assert!(test_rs.contains("part2(&Input::example(EXAMPLE_INPUT)), \"ABC\");"));
+    }
+    assert!(get_main_rs_content(day(2025, 5), &example, "tree").is_err());
+}
+
+#[test]
+fn built_in_templates_are_formatted() {
+    if try_rustfmt("fn main() {}\n").is_none() {
+        eprintln!("skipping built_in_templates_are_formatted because rustfmt is not installed");
+        return;
+    }
+    let examples = [
+        Example {
+            title: None,
+            input: None,
+            answers: Vec::new(),
+        },
+        Example {
+            title: Some("Day 12: Christmas Tree Farm".to_owned()),
+            input: Some("0:\n###\n##.\n\n4x4: 0 0\n".to_owned()),
+            answers: vec![Some("2".to_owned())],
+        },
+    ];
+    for flavor in ["plain", "grid", "graph", "ilp"] {
+        for example in &examples {
+            for day in [day(2025, 11), day(2025, 12)] {
+                let main_rs = get_main_rs_content(day, example, flavor).unwrap();
+                assert_eq!(rustfmt(main_rs.clone()), main_rs, "{flavor}");
+                let test_rs = get_test_rs_content(day, example, flavor).unwrap();
+                assert_eq!(rustfmt(test_rs.clone()), test_rs, "{flavor}");
+            }
+        }
+    }
+}
@@ -1,60 +1,348 @@
+// every day only uses a subset of these helpers and their re-exports
+#![allow(dead_code, unused_imports)]
+
+use chrono::{DateTime, TimeDelta, Utc};
+use std::cell::OnceCell;
 use std::collections::HashMap;
-use std::error::Error;
 use std::fs::{self};
-use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-fn hash(string: &str) -> String {
-    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    string.hash(&mut hasher);
-    hasher.finish().to_string()
-}
+#[path = "helpers/account.rs"]
+mod account;
+pub use account::*;
+
+#[path = "helpers/args.rs"]
+mod args;
+pub use args::*;
+
+#[path = "helpers/cache.rs"]
+mod cache;
+pub use cache::*;
+
+#[path = "helpers/dotenv.rs"]
+mod dotenv;
+pub use dotenv::*;
+
+#[path = "helpers/error.rs"]
+mod error;
+pub use error::*;
+
+#[path = "helpers/event.rs"]
+mod event;
+pub use event::*;
+
+#[path = "helpers/http.rs"]
+mod http;
+use http::*;
+
+#[path = "helpers/input.rs"]
+mod input;
+pub use input::*;
+
+#[path = "helpers/leaderboard.rs"]
+mod leaderboard;
+pub use leaderboard::*;
+
+#[path = "helpers/leaks.rs"]
+mod leaks;
+pub use leaks::*;
+
+#[path = "helpers/ledger.rs"]
+mod ledger;
+pub use ledger::*;
+
+#[path = "helpers/prefetch.rs"]
+mod prefetch;
+pub use prefetch::*;
+
+#[path = "helpers/puzzle.rs"]
+mod puzzle;
+pub use puzzle::*;
+
+#[path = "helpers/stats.rs"]
+mod stats;
+pub use stats::*;
 
-fn get_page(url: &str) -> Result<String, Box<dyn Error>> {
-    let session_cookie = format!(
-        "session={}",
-        read_dotenv()?.get("SESSION").ok_or(
-            "SESSION key missing in .env file. Please retrieve this from the cookie header on AoC"
-        )?
-    );
-    let cache_dir = "cache";
-    let cache_filename = format!("{}/{}.cache", &cache_dir, hash(url));
-    if let Ok(exists) = fs::exists(&cache_filename)
-        && exists
-        && let Ok(cached_data) = fs::read_to_string(&cache_filename)
-    {
-        return Ok(cached_data);
-    }
-    let client = reqwest::blocking::Client::new();
-    let data = client
-        .get(url)
-        .header(reqwest::header::COOKIE, session_cookie)
-        .send()?
-        .error_for_status()?
-        .text()?;
-
-    if let Ok(exists) = fs::exists(&cache_dir)
-        && !exists
-    {
-        fs::create_dir(&cache_dir)?;
-    }
-    fs::write(cache_filename, &data)?;
-    Ok(data)
+#[path = "helpers/submit.rs"]
+mod submit;
+pub use submit::*;
+
+#[path = "helpers/unlock.rs"]
+mod unlock;
+pub use unlock::*;
+
+/// the real site which links in generated files always point to, regardless
+/// of where pages are actually fetched from
+const AOC_URL: &str = "https://adventofcode.com";
+
+/// fetches (and caches) pages from AoC. Configured by these environment
+/// variables which can also be set in the .env file:
+///
+/// - `SESSION`: the session cookie used to authenticate with AoC
+/// - `SESSION_EXPIRES`: when the session cookie expires, as shown by the browser
+/// - `AOC_PROFILE`: use the `SESSION_<profile>` cookie instead, along with a
+///   separate cache and answer ledger for that account
+/// - `AOC_BASE_URL`: fetch from somewhere other than adventofcode.com (Ex: a mock server)
+/// - `AOC_OFFLINE`: when set to 1, fail instead of fetching pages which are not cached
+/// - `AOC_CACHE_DIR`: where pages are cached (Ex: cache/2025/5/input.txt), "cache" by default
+/// - `AOC_USER_AGENT`: identifies requests to AoC, ideally with contact details
+/// - `AOC_MIN_INTERVAL`: the minimum number of seconds between requests, 2 by default
+/// - `AOC_ROOT`: where the days are (Ex: 2025/5/answers.toml), by default the
+///   closest directory with a Cargo.toml
+pub struct Client {
+    base_url: String,
+    offline: bool,
+    cache_dir: PathBuf,
+    cache_root: PathBuf,
+    profile: Option<String>,
+    session: Option<String>,
+    /// when the session cookie expires, if it was noted down (Ex: SESSION_EXPIRES=2026-12-25)
+    session_expires: Option<DateTime<Utc>>,
+    clock: Box<dyn Clock>,
+    wait_for_unlock: bool,
+    http: reqwest::blocking::Client,
+    min_interval: Duration,
+    throttle_file: PathBuf,
+    root: PathBuf,
+    /// set once the legacy cache was migrated, which is only tried once
+    legacy_migrated: OnceCell<()>,
 }
 
-pub fn get_input(year: u16, number: u8) -> Result<String, Box<dyn Error>> {
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, number);
-    get_page(&url)
+/// the closest directory with a Cargo.toml in `directory` or its parents,
+/// which is the root of the repository
+pub fn find_root(directory: &Path) -> Option<PathBuf> {
+    directory
+        .ancestors()
+        .find(|directory| directory.join("Cargo.toml").is_file())
+        .map(Path::to_owned)
 }
 
-pub fn read_dotenv() -> Result<HashMap<String, String>, Box<dyn Error>> {
-    Ok(fs::read_to_string(".env")?
-        .split("\n")
-        .filter_map(|line| {
-            let mut x = line.split("=");
-            return Option::zip(
-                x.nth(0).map(|s| s.to_owned()),
-                x.nth(0).map(|s| s.to_owned()),
-            );
+impl Client {
+    /// configures a client from the process environment, falling back to the
+    /// closest .env file in the current directory or any of its parents
+    pub fn from_env() -> Result<Self, AocError> {
+        let dotenv = match find_dotenv(&std::env::current_dir()?) {
+            Some(path) => read_dotenv(&path)?,
+            None => HashMap::new(),
+        };
+        Self::from_vars(|key| std::env::var(key).ok().or_else(|| dotenv.get(key).cloned()))
+    }
+
+    /// configures a client from an arbitrary source of variables
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, AocError> {
+        Self::from_vars_in(&std::env::current_dir()?, var)
+    }
+
+    /// configures a client from an arbitrary source of variables as if it was
+    /// run from `directory`, which is used to find the root of the repository
+    pub fn from_vars_in(
+        directory: &Path,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, AocError> {
+        let profile = var("AOC_PROFILE").filter(|profile| !profile.trim().is_empty());
+        if let Some(profile) = &profile
+            && !profile
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+        {
+            return Err(AocError::Config(format!(
+                "AOC_PROFILE should only contain letters, numbers, '_' and '-' but was '{profile}'"
+            )));
+        }
+        let min_interval = match var("AOC_MIN_INTERVAL") {
+            Some(interval) => interval
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                // the interval is added to the time of the last request
+                .filter(|interval| TimeDelta::from_std(*interval).is_ok())
+                .ok_or(AocError::Config(format!(
+                    "AOC_MIN_INTERVAL should be a number of seconds but was '{interval}'"
+                )))?,
+            None => DEFAULT_MIN_INTERVAL,
+        };
+        let expires_key = format!("{}_EXPIRES", session_key(profile.as_deref()));
+        let session_expires = match var(&expires_key) {
+            Some(expires) => Some(parse_expiry(&expires).ok_or(AocError::Config(format!(
+                "{expires_key} should be a date (Ex: 2026-12-25) but was '{expires}'"
+            )))?),
+            None => None,
+        };
+        let root = var("AOC_ROOT")
+            .map(PathBuf::from)
+            .or_else(|| find_root(directory))
+            .unwrap_or_default();
+        // a relative cache directory is relative to the root, not to wherever
+        // the binary happens to run from
+        let cache_root = root.join(var("AOC_CACHE_DIR").unwrap_or("cache".to_owned()));
+        let cache_dir = match &profile {
+            Some(profile) => cache_root.join("profiles").join(profile),
+            None => cache_root.clone(),
+        };
+        Ok(Client {
+            base_url: var("AOC_BASE_URL")
+                .map(|url| url.trim_end_matches('/').to_owned())
+                .unwrap_or(AOC_URL.to_owned()),
+            offline: var("AOC_OFFLINE")
+                .is_some_and(|offline| matches!(offline.trim(), "1" | "true" | "yes")),
+            cache_dir,
+            session: var(&session_key(profile.as_deref())),
+            session_expires,
+            profile,
+            clock: Box::new(SystemClock),
+            wait_for_unlock: false,
+            http: http_client(&var("AOC_USER_AGENT").unwrap_or(DEFAULT_USER_AGENT.to_owned()))?,
+            min_interval,
+            throttle_file: cache_root.join("last-request"),
+            cache_root,
+            root,
+            legacy_migrated: OnceCell::new(),
         })
-        .collect::<HashMap<String, String>>())
+    }
+
+    /// replaces the clock used to decide whether puzzles have unlocked
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// whether fetching a puzzle which has not unlocked yet should wait for
+    /// it to unlock instead of failing
+    pub fn wait_for_unlock(mut self, wait: bool) -> Self {
+        self.wait_for_unlock = wait;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// the selected profile or `None` for the default account
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// where pages are cached, which is separate for each profile (Ex: cache/profiles/alice)
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// the cache directory shared by every profile (Ex: cache)
+    pub fn cache_root(&self) -> &Path {
+        &self.cache_root
+    }
+
+    /// the root of the repository, which contains a directory for every year
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn session_cookie(&self) -> Result<String, AocError> {
+        Ok(format!(
+            "session={}",
+            self.session
+                .as_ref()
+                .ok_or(AocError::MissingSession(session_key(self.profile())))?
+        ))
+    }
+
+    /// AoC did not accept the session cookie of this client's profile
+    fn session_expired(&self) -> AocError {
+        AocError::SessionExpired(session_key(self.profile()))
+    }
+
+    /// fails instead of contacting AoC when running in offline mode
+    fn ensure_online(&self, page: &Page, url: &str) -> Result<(), AocError> {
+        if !self.offline {
+            return Ok(());
+        }
+        // the page is only ever fetched while it is cached if the cached copy
+        // cannot be trusted
+        let path = self.cache_dir.join(page.cache_path());
+        match path.exists() {
+            true => Err(AocError::CacheCorrupt {
+                reason: self
+                    .verify_cache_entry(page)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                path,
+            }),
+            false => Err(AocError::Offline(url.to_owned())),
+        }
+    }
+
+    fn get_page(&self, page: &Page) -> Result<String, AocError> {
+        match self.read_cache(page) {
+            Some(cached_data) => Ok(cached_data),
+            None => self.fetch_page(page),
+        }
+    }
+
+    /// downloads a page even if it is already cached and then replaces the cached copy
+    fn fetch_page(&self, page: &Page) -> Result<String, AocError> {
+        let url = format!("{}{}", self.base_url, page.url_path());
+        self.ensure_online(page, &url)?;
+        if let Some(day) = page.day() {
+            self.ensure_unlocked(page.year(), day)?;
+        }
+        let (status, data) = self.send(
+            self.http
+                .get(&url)
+                .header(reqwest::header::COOKIE, self.session_cookie()?),
+        )?;
+        if !(200..300).contains(&status) {
+            return Err(AocError::from_response(
+                self.profile(),
+                page.year(),
+                page.day(),
+                &url,
+                status,
+                &data,
+            ));
+        }
+        if data.is_empty() {
+            return Err(AocError::UnexpectedResponse(format!(
+                "{url} returned an empty page"
+            )));
+        }
+        self.write_cache(page, &data, &self.cache_metadata(&url, status, &data))?;
+        Ok(data)
+    }
+
+    pub fn get_input(&self, year: u16, number: u8) -> Result<Input, AocError> {
+        let input = self.cache_input(year, number)?;
+        self.record_opened(year, number);
+        Ok(input)
+    }
+
+    /// the input of a day, fetched if it is not cached yet, without counting
+    /// the day as opened since nothing is being solved yet
+    pub fn cache_input(&self, year: u16, number: u8) -> Result<Input, AocError> {
+        Ok(Input::new(
+            &self.get_page(&Page::Input { year, day: number })?,
+        ))
+    }
+}
+
+/// the variable holding the session cookie for a profile (Ex: SESSION_alice)
+fn session_key(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("SESSION_{profile}"),
+        None => "SESSION".to_owned(),
+    }
+}
+
+/// the input of a day, waiting for it to unlock when `wait` is set
+pub fn get_input(year: u16, number: u8, wait: bool) -> Result<Input, AocError> {
+    Client::from_env()?
+        .wait_for_unlock(wait)
+        .get_input(year, number)
 }
@@ -0,0 +1,115 @@
+use super::{AocError, Client, Event};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use reqwest::header::{COOKIE, HeaderMap, SET_COOKIE};
+use scraper::{Html, Node, Selector};
+
+/// the account which the session cookie belongs to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Account {
+    /// the name shown in the header (Ex: (anonymous user #12345))
+    pub name: String,
+    /// whether the account has AoC++
+    pub supporter: bool,
+    pub session_expires: Option<DateTime<Utc>>,
+}
+
+impl Account {
+    /// the number of whole days until the session cookie expires, which is
+    /// negative once it has expired
+    pub fn days_until_expiry(&self, now: DateTime<Utc>) -> Option<i64> {
+        Some((self.session_expires? - now).num_days())
+    }
+}
+
+/// parses a date like the browser shows for cookies (Ex: 2026-12-25 or
+/// 2026-12-25T05:00:00Z)
+pub(super) fn parse_expiry(expires: &str) -> Option<DateTime<Utc>> {
+    let expires = expires.trim();
+    DateTime::parse_from_rfc3339(expires)
+        .map(Into::into)
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(expires, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+        })
+}
+
+/// when the session cookie expires according to a response which set it again
+fn session_expiry(headers: &HeaderMap, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let cookie = headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|cookie| cookie.to_str().ok())
+        .find(|cookie| cookie.starts_with("session="))?;
+    cookie.split(';').skip(1).find_map(|attribute| {
+        let (name, value) = attribute.trim().split_once('=')?;
+        match name.to_ascii_lowercase().as_str() {
+            "max-age" => Some(now + TimeDelta::seconds(value.parse().ok()?)),
+            "expires" => Some(DateTime::parse_from_rfc2822(value).ok()?.into()),
+            _ => None,
+        }
+    })
+}
+
+/// the name and AoC++ badge in the header of every page for logged in users
+pub fn parse_account(html: &str) -> Result<Account, AocError> {
+    let document = Html::parse_document(html);
+    let user = document
+        .select(&Selector::parse("div.user").expect("selector should always be valid"))
+        .next()
+        .ok_or(AocError::UnexpectedResponse(
+            "the page does not show who is logged in".to_owned(),
+        ))?;
+    // the star count and badge are inside their own elements
+    let name = user
+        .children()
+        .filter_map(|child| match child.value() {
+            Node::Text(text) => Some(text.to_string()),
+            _ => None,
+        })
+        .collect::<String>();
+    let supporter = user
+        .select(&Selector::parse(".supporter-badge").expect("selector should always be valid"))
+        .next()
+        .is_some();
+    Ok(Account {
+        name: name.trim().to_owned(),
+        supporter,
+        session_expires: None,
+    })
+}
+
+/// checks the session cookie by fetching a page which is only shown to
+/// logged in users
+pub fn whoami() -> Result<Account, AocError> {
+    Client::from_env()?.whoami()
+}
+
+impl Client {
+    pub fn whoami(&self) -> Result<Account, AocError> {
+        let year = Event::latest(self.clock.now()).year();
+        let url = format!("{}/{year}/settings", self.base_url);
+        if self.offline {
+            return Err(AocError::Config(format!(
+                "cannot check the session with {url} because AOC_OFFLINE is set"
+            )));
+        }
+        let (status, headers, html) =
+            self.send_with_headers(self.http.get(&url).header(COOKIE, self.session_cookie()?))?;
+        if !(200..300).contains(&status) {
+            return Err(AocError::from_response(
+                self.profile(),
+                year,
+                None,
+                &url,
+                status,
+                &html,
+            ));
+        }
+        let mut account = parse_account(&html)?;
+        account.session_expires =
+            session_expiry(&headers, self.clock.now()).or(self.session_expires);
+        Ok(account)
+    }
+}
@@ -0,0 +1,133 @@
+use super::{AocError, Client, Input};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str =
+    "usage: cargo run --bin <year>_<day> -- [--input <path> | - | --example] [--wait]
+
+  --input <path>  solve the input in a file instead of the AoC input
+  --input -, -    solve the input from stdin
+  --example       solve the example which the tests use
+  --wait          wait for the puzzle to unlock before fetching the AoC input";
+
+/// where the input for a day comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Aoc,
+    File(PathBuf),
+    Stdin,
+    Example,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Aoc => write!(f, "the AoC input"),
+            InputSource::File(path) => write!(f, "the input in {}", path.display()),
+            InputSource::Stdin => write!(f, "the input from stdin"),
+            InputSource::Example => write!(f, "the example"),
+        }
+    }
+}
+
+/// the arguments every day accepts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub source: InputSource,
+    pub wait: bool,
+}
+
+pub fn parse_day_args(args: impl IntoIterator<Item = String>) -> Result<DayArgs, AocError> {
+    let mut parsed = DayArgs {
+        source: InputSource::Aoc,
+        wait: false,
+    };
+    let mut args = args.into_iter();
+    let mut set_source = |source: InputSource| match parsed.source {
+        InputSource::Aoc => {
+            parsed.source = source;
+            Ok(())
+        }
+        _ => Err(AocError::Config(format!(
+            "only one of --input, - and --example can be used\n\n{USAGE}"
+        ))),
+    };
+    let mut wait = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next().as_deref() {
+                Some("-") => set_source(InputSource::Stdin)?,
+                Some(path) => set_source(InputSource::File(PathBuf::from(path)))?,
+                None => {
+                    return Err(AocError::Config(format!(
+                        "--input should be followed by a path\n\n{USAGE}"
+                    )));
+                }
+            },
+            "-" => set_source(InputSource::Stdin)?,
+            "--example" => set_source(InputSource::Example)?,
+            "--wait" => wait = true,
+            _ => match arg.strip_prefix("--input=") {
+                Some("-") => set_source(InputSource::Stdin)?,
+                Some(path) => set_source(InputSource::File(PathBuf::from(path)))?,
+                None => {
+                    return Err(AocError::Config(format!(
+                        "unknown argument '{arg}'\n\n{USAGE}"
+                    )));
+                }
+            },
+        }
+    }
+    parsed.wait = wait;
+    Ok(parsed)
+}
+
+/// reads the input for a day from wherever it was asked to come from.
+/// `example` is the fixture used by the day's tests. The [`Client`] is only
+/// configured for the AoC input so that the others work without a session
+pub fn read_input(
+    source: &InputSource,
+    year: u16,
+    day: u8,
+    example: &str,
+    client: impl FnOnce() -> Result<Client, AocError>,
+) -> Result<Input, AocError> {
+    match source {
+        InputSource::Aoc => client()?.get_input(year, day),
+        InputSource::File(path) => Ok(Input::new(&fs::read_to_string(path)?)),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(Input::new(&input))
+        }
+        InputSource::Example => Ok(Input::example(example)),
+    }
+}
+
+/// the input for a day based on the program's arguments (see [`parse_day_args`]),
+/// which also reports where the input came from
+pub fn get_input_from_args(year: u16, day: u8, example: &str) -> Result<Input, AocError> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{USAGE}");
+        process::exit(0);
+    }
+    let args = parse_day_args(args)?;
+    let mut profile = None;
+    let input = read_input(&args.source, year, day, example, || {
+        let client = Client::from_env()?.wait_for_unlock(args.wait);
+        profile = client.profile().map(str::to_owned);
+        Ok(client)
+    })?;
+    match (&args.source, profile) {
+        (InputSource::Aoc, Some(profile)) => {
+            eprintln!("Using {} for day {day} of {year} as {profile}", args.source)
+        }
+        (InputSource::Aoc, None) => eprintln!("Using {} for day {day} of {year}", args.source),
+        _ => eprintln!("Using {}", args.source),
+    }
+    Ok(input)
+}
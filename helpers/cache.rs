@@ -0,0 +1,458 @@
+use super::{AOC_URL, AocError, Client, Event};
+use chrono::{DateTime, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Value};
+
+/// a page on AoC which can be fetched and cached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Input {
+        year: u16,
+        day: u8,
+    },
+    Puzzle {
+        year: u16,
+        day: u8,
+    },
+    /// the JSON export of a private leaderboard, identified by its owner's id
+    Leaderboard {
+        year: u16,
+        id: u64,
+    },
+    /// the personal times, ranks and scores of the session's account
+    Stats {
+        year: u16,
+    },
+}
+
+impl Page {
+    /// the path of the page on AoC (Ex: /2025/day/5/input)
+    pub fn url_path(&self) -> String {
+        match self {
+            Page::Input { year, day } => format!("/{year}/day/{day}/input"),
+            Page::Puzzle { year, day } => format!("/{year}/day/{day}"),
+            Page::Leaderboard { year, id } => format!("/{year}/leaderboard/private/view/{id}.json"),
+            Page::Stats { year } => format!("/{year}/leaderboard/self"),
+        }
+    }
+
+    /// the path of the cached page relative to the cache directory (Ex: 2025/5/input.txt)
+    pub fn cache_path(&self) -> PathBuf {
+        match self {
+            Page::Input { year, day } => PathBuf::from(format!("{year}/{day}/input.txt")),
+            Page::Puzzle { year, day } => PathBuf::from(format!("{year}/{day}/puzzle.html")),
+            Page::Leaderboard { year, id } => {
+                PathBuf::from(format!("{year}/leaderboard/{id}.json"))
+            }
+            Page::Stats { year } => PathBuf::from(format!("{year}/self.html")),
+        }
+    }
+
+    /// inverse of [`Page::cache_path`]
+    fn from_cache_path(path: &Path) -> Option<Self> {
+        let components = path
+            .iter()
+            .map(|component| component.to_str())
+            .collect::<Option<Vec<_>>>()?;
+        let (year, components) = components.split_first()?;
+        let year = year.parse().ok()?;
+        match components {
+            ["self.html"] => Some(Page::Stats { year }),
+            ["leaderboard", file] => Some(Page::Leaderboard {
+                year,
+                id: file.strip_suffix(".json")?.parse().ok()?,
+            }),
+            [day, "input.txt"] => Some(Page::Input {
+                year,
+                day: day.parse().ok()?,
+            }),
+            [day, "puzzle.html"] => Some(Page::Puzzle {
+                year,
+                day: day.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        match self {
+            Page::Input { year, .. }
+            | Page::Puzzle { year, .. }
+            | Page::Leaderboard { year, .. }
+            | Page::Stats { year } => *year,
+        }
+    }
+
+    /// the day the page belongs to, or `None` for pages about the whole event
+    pub fn day(&self) -> Option<u8> {
+        match self {
+            Page::Input { day, .. } | Page::Puzzle { day, .. } => Some(*day),
+            Page::Leaderboard { .. } | Page::Stats { .. } => None,
+        }
+    }
+}
+
+/// details about how a cached page was fetched, stored next to it as
+/// `<name>.meta.toml` (Ex: cache/2025/5/input.meta.toml)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheMetadata {
+    pub url: String,
+    pub fetched: DateTime<Utc>,
+    pub status: u16,
+    pub length: u64,
+    /// [`session_fingerprint`] of the session which fetched the page
+    pub session: String,
+}
+
+impl CacheMetadata {
+    fn parse(content: &str) -> Result<Self, String> {
+        let document = content
+            .parse::<DocumentMut>()
+            .map_err(|error| error.to_string())?;
+        let field = |name: &str| {
+            document
+                .get(name)
+                .and_then(|item| item.as_value())
+                .ok_or(format!("cache metadata is missing '{name}'"))
+        };
+        let fetched = field("fetched")?
+            .as_datetime()
+            .ok_or("fetched should be a datetime")?;
+        Ok(CacheMetadata {
+            url: field("url")?
+                .as_str()
+                .ok_or("url should be a string")?
+                .to_owned(),
+            fetched: DateTime::parse_from_rfc3339(&fetched.to_string())
+                .map_err(|error| error.to_string())?
+                .into(),
+            status: field("status")?
+                .as_integer()
+                .ok_or("status should be an integer")?
+                .try_into()
+                .map_err(|_| "status should be a valid HTTP status")?,
+            length: field("length")?
+                .as_integer()
+                .ok_or("length should be an integer")?
+                .try_into()
+                .map_err(|_| "length should not be negative")?,
+            session: field("session")?
+                .as_str()
+                .ok_or("session should be a string")?
+                .to_owned(),
+        })
+    }
+
+    fn to_toml(&self) -> String {
+        let mut document = DocumentMut::new();
+        document["url"] = Item::Value(self.url.as_str().into());
+        document["fetched"] = Item::Value(Value::from(
+            self.fetched
+                .to_rfc3339_opts(SecondsFormat::Secs, true)
+                .parse::<toml_edit::Datetime>()
+                .expect("RFC 3339 timestamps should always be valid TOML datetimes"),
+        ));
+        document["status"] = Item::Value((self.status as i64).into());
+        document["length"] = Item::Value((self.length as i64).into());
+        document["session"] = Item::Value(self.session.as_str().into());
+        document.to_string()
+    }
+}
+
+/// a short, stable identifier for a session which does not reveal the cookie
+pub fn session_fingerprint(session: &str) -> String {
+    Sha256::digest(session.as_bytes())
+        .iter()
+        .take(6)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// a page in the cache along with its metadata (if it has any)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntry {
+    pub page: Page,
+    pub path: PathBuf,
+    pub metadata: Option<CacheMetadata>,
+}
+
+/// reasons why a cached page should not be trusted
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheProblem {
+    /// cached before metadata was recorded so nothing can be verified
+    MissingMetadata,
+    InvalidMetadata(String),
+    Status(u16),
+    Empty,
+    LengthMismatch {
+        expected: u64,
+        actual: u64,
+    },
+    /// fetched by a different session so it may belong to someone else
+    DifferentSession,
+    /// an input which contains an HTML page or an error message from AoC
+    ErrorPage,
+}
+
+impl CacheProblem {
+    /// whether the cached page is still used despite this problem
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, CacheProblem::MissingMetadata)
+    }
+}
+
+impl fmt::Display for CacheProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheProblem::MissingMetadata => write!(f, "no metadata"),
+            CacheProblem::InvalidMetadata(error) => write!(f, "invalid metadata: {error}"),
+            CacheProblem::Status(status) => write!(f, "fetched with HTTP status {status}"),
+            CacheProblem::Empty => write!(f, "empty"),
+            CacheProblem::LengthMismatch { expected, actual } => {
+                write!(f, "expected {expected} bytes but found {actual}")
+            }
+            CacheProblem::DifferentSession => write!(f, "fetched by a different session"),
+            CacheProblem::ErrorPage => write!(f, "contains an error page"),
+        }
+    }
+}
+
+impl Client {
+    fn cache_filename(&self, page: &Page) -> PathBuf {
+        self.cache_dir.join(page.cache_path())
+    }
+
+    fn metadata_filename(&self, page: &Page) -> PathBuf {
+        self.cache_filename(page).with_extension("meta.toml")
+    }
+
+    /// the cached copy of a page, unless it is missing or cannot be trusted
+    pub(super) fn read_cache(&self, page: &Page) -> Option<String> {
+        let cache_filename = self.cache_filename(page);
+        if !cache_filename.exists() {
+            self.legacy_migrated.get_or_init(|| {
+                if let Err(error) = self.migrate_legacy_cache() {
+                    eprintln!("Failed to migrate the legacy cache: {error}");
+                }
+            });
+        }
+        let data = fs::read_to_string(&cache_filename).ok()?;
+        let problems = self.verify_cache_entry(page);
+        match problems.iter().all(CacheProblem::is_trusted) {
+            true => Some(data),
+            false => None,
+        }
+    }
+
+    pub(super) fn write_cache(
+        &self,
+        page: &Page,
+        data: &str,
+        metadata: &CacheMetadata,
+    ) -> Result<(), AocError> {
+        let cache_filename = self.cache_filename(page);
+        if let Some(parent) = cache_filename.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(cache_filename, data)?;
+        fs::write(self.metadata_filename(page), metadata.to_toml())?;
+        Ok(())
+    }
+
+    /// the metadata recorded for a page fetched right now by this client
+    pub(super) fn cache_metadata(&self, url: &str, status: u16, data: &str) -> CacheMetadata {
+        CacheMetadata {
+            url: url.to_owned(),
+            fetched: self.clock.now(),
+            status,
+            length: data.len() as u64,
+            session: self
+                .session
+                .as_deref()
+                .map(session_fingerprint)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// when a page was cached, as long as its metadata was recorded
+    pub(super) fn cached_at(&self, page: &Page) -> Option<DateTime<Utc>> {
+        let content = fs::read_to_string(self.metadata_filename(page)).ok()?;
+        Some(CacheMetadata::parse(&content).ok()?.fetched)
+    }
+
+    /// every cached page, optionally limited to a single year and/or day
+    pub fn cache_entries(
+        &self,
+        year: Option<u16>,
+        day: Option<u8>,
+    ) -> Result<Vec<CacheEntry>, AocError> {
+        let mut entries = Vec::new();
+        let mut directories = vec![self.cache_dir.clone()];
+        while let Some(directory) = directories.pop() {
+            let Ok(children) = fs::read_dir(&directory) else {
+                continue;
+            };
+            for child in children {
+                let path = child?.path();
+                if path.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+                let relative_path = path
+                    .strip_prefix(&self.cache_dir)
+                    .expect("entries should always be inside the cache directory");
+                let Some(page) = Page::from_cache_path(relative_path) else {
+                    continue;
+                };
+                if year.is_some_and(|year| year != page.year())
+                    || day.is_some_and(|day| Some(day) != page.day())
+                {
+                    continue;
+                }
+                entries.push(CacheEntry {
+                    page,
+                    metadata: fs::read_to_string(self.metadata_filename(&page))
+                        .ok()
+                        .and_then(|content| CacheMetadata::parse(&content).ok()),
+                    path,
+                });
+            }
+        }
+        entries.sort_by_key(|entry| (entry.page.year(), entry.page.day(), entry.path.clone()));
+        Ok(entries)
+    }
+
+    /// checks a cached page against its metadata
+    pub fn verify_cache_entry(&self, page: &Page) -> Vec<CacheProblem> {
+        let mut problems = Vec::new();
+        let data = fs::read(self.cache_filename(page)).unwrap_or_default();
+        if data.is_empty() {
+            problems.push(CacheProblem::Empty);
+        }
+        if let Page::Input { .. } = page {
+            let text = String::from_utf8_lossy(&data);
+            if text.trim_start().starts_with('<')
+                || text.starts_with("Puzzle inputs differ by user")
+                || text.starts_with("Please don't repeatedly request this endpoint")
+            {
+                problems.push(CacheProblem::ErrorPage);
+            }
+        }
+        let metadata = match fs::read_to_string(self.metadata_filename(page)) {
+            Ok(content) => CacheMetadata::parse(&content),
+            Err(_) => {
+                problems.push(CacheProblem::MissingMetadata);
+                return problems;
+            }
+        };
+        let metadata = match metadata {
+            Ok(metadata) => metadata,
+            Err(error) => {
+                problems.push(CacheProblem::InvalidMetadata(error));
+                return problems;
+            }
+        };
+        if !(200..300).contains(&metadata.status) {
+            problems.push(CacheProblem::Status(metadata.status));
+        }
+        if metadata.length != data.len() as u64 {
+            problems.push(CacheProblem::LengthMismatch {
+                expected: metadata.length,
+                actual: data.len() as u64,
+            });
+        }
+        if let Some(session) = &self.session
+            && session_fingerprint(session) != metadata.session
+        {
+            problems.push(CacheProblem::DifferentSession);
+        }
+        problems
+    }
+
+    /// deletes cached pages (and their metadata), optionally limited to a
+    /// single year and/or day, and returns how many were deleted
+    pub fn purge_cache(&self, year: Option<u16>, day: Option<u8>) -> Result<usize, AocError> {
+        let entries = self.cache_entries(year, day)?;
+        for entry in &entries {
+            fs::remove_file(&entry.path)?;
+            let metadata_filename = self.metadata_filename(&entry.page);
+            if metadata_filename.exists() {
+                fs::remove_file(metadata_filename)?;
+            }
+        }
+        Ok(entries.len())
+    }
+
+    /// pages used to be cached as `<hash of url>.cache` directly inside the
+    /// cache directory, before there were profiles. Moves every such file that
+    /// can still be identified (by hashing the url of every page that could
+    /// exist) into the current layout of the default profile. The rest, and
+    /// any which would replace a page that is already cached, are moved aside
+    /// into `legacy/` so that they are only reported once
+    pub fn migrate_legacy_cache(&self) -> Result<usize, AocError> {
+        let Ok(entries) = fs::read_dir(&self.cache_root) else {
+            return Ok(0);
+        };
+        let legacy_files = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "cache")
+            })
+            .collect::<Vec<_>>();
+        if legacy_files.is_empty() {
+            return Ok(0);
+        }
+
+        let mut migrated = 0;
+        for event in Event::all(self.clock.now()) {
+            for day in event.all_days() {
+                let (year, day) = (day.year(), day.number());
+                for page in [Page::Input { year, day }, Page::Puzzle { year, day }] {
+                    let legacy_filename = self.cache_root.join(format!(
+                        "{}.cache",
+                        legacy_hash(&format!("{AOC_URL}{}", page.url_path()))
+                    ));
+                    let cache_filename = self.cache_root.join(page.cache_path());
+                    // a page fetched since is newer than the legacy copy
+                    if legacy_files.contains(&legacy_filename) && !cache_filename.exists() {
+                        if let Some(parent) = cache_filename.parent() {
+                            fs::create_dir_all(parent)?;
+                        }
+                        fs::rename(&legacy_filename, &cache_filename)?;
+                        migrated += 1;
+                    }
+                }
+            }
+        }
+        if migrated < legacy_files.len() {
+            // the hash is not stable between compiler versions so some files
+            // may never be identified and are left for the user to clean up
+            let legacy_dir = self.cache_root.join("legacy");
+            fs::create_dir_all(&legacy_dir)?;
+            for legacy_filename in legacy_files.iter().filter(|path| path.exists()) {
+                if let Some(name) = legacy_filename.file_name() {
+                    fs::rename(legacy_filename, legacy_dir.join(name))?;
+                }
+            }
+            eprintln!(
+                "{} legacy cache files could not be migrated and were moved to {}",
+                legacy_files.len() - migrated,
+                legacy_dir.display()
+            );
+        }
+        Ok(migrated)
+    }
+}
+
+/// the file name that pages were cached under before [`Page::cache_path`]
+pub fn legacy_hash(string: &str) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    string.hash(&mut hasher);
+    hasher.finish().to_string()
+}
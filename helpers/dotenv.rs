@@ -0,0 +1,174 @@
+use super::AocError;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// a line in a .env file which could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotenvError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for DotenvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for DotenvError {}
+
+/// the closest .env file in `directory` or any of its parents
+pub fn find_dotenv(directory: &Path) -> Option<PathBuf> {
+    directory
+        .ancestors()
+        .map(|directory| directory.join(".env"))
+        .find(|path| path.is_file())
+}
+
+pub fn read_dotenv(path: &Path) -> Result<HashMap<String, String>, AocError> {
+    let content = fs::read_to_string(path)?;
+    parse_dotenv(&content).map_err(|error| AocError::Dotenv {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// parses the contents of a .env file. Supports:
+///
+/// - blank lines and `#` comments (including after values)
+/// - an optional `export ` before the key
+/// - `'single quoted'` values which are taken literally
+/// - `"double quoted"` values with `\n`, `\r`, `\t`, `\"` and `\\` escapes
+/// - quoted values which span several lines
+/// - CRLF line endings
+pub fn parse_dotenv(content: &str) -> Result<HashMap<String, String>, DotenvError> {
+    let mut vars = HashMap::new();
+    let mut lines = content
+        .strip_prefix('\u{feff}')
+        .unwrap_or(content)
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+    while let Some((number, line)) = lines.next() {
+        let error = |message: String| DotenvError {
+            line: number,
+            message,
+        };
+        // only the end of unquoted values is trimmed since whitespace inside
+        // quotes is part of the value, even at the end of a line
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let assignment = line
+            .strip_prefix("export")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map(str::trim_start)
+            .unwrap_or(line);
+        let Some((key, value)) = assignment.split_once('=') else {
+            return Err(error(format!(
+                "expected KEY=VALUE but found '{}'",
+                line.trim_end()
+            )));
+        };
+        let key = key.trim_end();
+        if !is_valid_key(key) {
+            return Err(error(format!(
+                "'{key}' is not a valid key, keys should only contain letters, numbers and '_'"
+            )));
+        }
+
+        let value = value.trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                // quoted values continue until the closing quote, even when
+                // that is on a later line
+                let mut quoted = value[1..].to_owned();
+                let end = loop {
+                    if let Some(end) = closing_quote(&quoted, quote) {
+                        break end;
+                    }
+                    let Some((_, next)) = lines.next() else {
+                        return Err(error(format!("{quote} is never closed")));
+                    };
+                    quoted.push('\n');
+                    quoted.push_str(next);
+                };
+                let rest = quoted[end + 1..].trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(error(format!(
+                        "unexpected '{rest}' after the closing {quote}"
+                    )));
+                }
+                match quote {
+                    '"' => unescape(&quoted[..end]),
+                    _ => quoted[..end].to_owned(),
+                }
+            }
+            _ => {
+                // "#" only starts a comment after whitespace so that values
+                // like "abc#123" are kept intact
+                let end = value
+                    .char_indices()
+                    .find(|&(index, char)| {
+                        char == '#' && (index == 0 || value[..index].ends_with(char::is_whitespace))
+                    })
+                    .map(|(index, _)| index)
+                    .unwrap_or(value.len());
+                value[..end].trim_end().to_owned()
+            }
+        };
+        vars.insert(key.to_owned(), value);
+    }
+    Ok(vars)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with(|char: char| char.is_ascii_digit())
+        && key
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+/// the index of the quote which closes a value, skipping escaped quotes in
+/// double quoted values
+fn closing_quote(value: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, char) in value.char_indices() {
+        match char {
+            _ if escaped => escaped = false,
+            '\\' if quote == '"' => escaped = true,
+            _ if char == quote => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            unescaped.push(char);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(char @ ('"' | '\\' | '\'' | '$')) => unescaped.push(char),
+            // unknown escapes are kept as they are
+            Some(char) => {
+                unescaped.push('\\');
+                unescaped.push(char);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
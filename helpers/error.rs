@@ -0,0 +1,215 @@
+use super::{DotenvError, Event, Leak, Refusal, days_in_event, session_key};
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+use std::process;
+
+/// everything that can go wrong while talking to AoC or managing the files
+/// that are kept alongside each day
+#[derive(Debug)]
+pub enum AocError {
+    /// the variable holding the session cookie is not set (Ex: SESSION_alice)
+    MissingSession(String),
+    /// AoC did not accept the session cookie held by this variable, which
+    /// usually means it expired
+    SessionExpired(String),
+    PuzzleNotUnlocked {
+        year: u16,
+        day: u8,
+    },
+    /// there was no event in the year (Ex: 2014)
+    NoSuchEvent(u16),
+    /// the day is not part of the event for the year (Ex: day 13 of 2025)
+    NoSuchDay {
+        year: u16,
+        day: u8,
+    },
+    RateLimited,
+    Http {
+        url: String,
+        status: u16,
+    },
+    /// AoC could not be reached at all
+    Network(reqwest::Error),
+    /// the url is not cached and fetching it is not allowed
+    Offline(String),
+    CacheCorrupt {
+        path: PathBuf,
+        reason: String,
+    },
+    Io(std::io::Error),
+    Dotenv {
+        path: PathBuf,
+        error: DotenvError,
+    },
+    Config(String),
+    InvalidPart(u8),
+    InvalidLedger {
+        path: PathBuf,
+        reason: String,
+    },
+    /// a line of an [`Input`](super::Input) could not be parsed
+    InvalidInput {
+        line: usize,
+        reason: String,
+    },
+    /// the ledger knows that submitting the answer is pointless
+    Refused(Refusal),
+    /// AoC responded successfully but with a page that could not be understood
+    UnexpectedResponse(String),
+    Git(git2::Error),
+    /// inputs or the session cookie could end up in the repository
+    Leaking(Vec<Leak>),
+}
+
+impl AocError {
+    /// classifies an unsuccessful response from AoC for a page of a single
+    /// day, or of the whole event when there is no day
+    pub(super) fn from_response(
+        profile: Option<&str>,
+        year: u16,
+        day: Option<u8>,
+        url: &str,
+        status: u16,
+        body: &str,
+    ) -> Self {
+        if let Some(day) = day
+            && (body.contains("Please don't repeatedly request this endpoint before it unlocks")
+                // puzzle pages which have not unlocked yet do not exist
+                || (status == 404 && !url.ends_with("/input")))
+        {
+            return AocError::PuzzleNotUnlocked { year, day };
+        }
+        if body.contains("Puzzle inputs differ by user")
+            || body.contains("please identify yourself")
+        {
+            return AocError::SessionExpired(session_key(profile));
+        }
+        match status {
+            429 => AocError::RateLimited,
+            _ => AocError::Http {
+                url: url.to_owned(),
+                status,
+            },
+        }
+    }
+
+    /// prints the error and exits instead of panicking, for use in `main`
+    pub fn exit(self) -> ! {
+        eprintln!("Error: {self}");
+        process::exit(1)
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingSession(key) => write!(
+                f,
+                "{key} key missing in .env file. Please retrieve this from the cookie header on AoC"
+            ),
+            AocError::SessionExpired(key) => write!(
+                f,
+                "AoC did not accept the session cookie, it has probably expired. Log in to AoC again and update the {key} key in the .env file"
+            ),
+            AocError::PuzzleNotUnlocked { year, day } => write!(
+                f,
+                "day {day} of {year} has not unlocked yet. Puzzles unlock at midnight US Eastern time, run with --wait to fetch it as soon as it does"
+            ),
+            AocError::NoSuchEvent(year) => write!(
+                f,
+                "there was no event in {year}, the first was in {}",
+                Event::FIRST_YEAR
+            ),
+            AocError::NoSuchDay { year, day } => match days_in_event(*year) {
+                Some(days) => write!(f, "{year} only has {days} days so there is no day {day}"),
+                None => write!(
+                    f,
+                    "there was no event in {year}, the first was in {}",
+                    Event::FIRST_YEAR
+                ),
+            },
+            AocError::RateLimited => write!(
+                f,
+                "AoC is rate limiting requests, wait a few minutes before trying again"
+            ),
+            AocError::Http { url, status } => write!(f, "{url} responded with HTTP {status}"),
+            AocError::Network(error) => write!(
+                f,
+                "could not reach AoC ({error}). Check the network connection or set AOC_OFFLINE=1 to only use cached pages"
+            ),
+            AocError::Offline(url) => write!(f, "{url} is not cached and AOC_OFFLINE is set"),
+            AocError::CacheCorrupt { path, reason } => write!(
+                f,
+                "the cached page {} is corrupt ({reason}). Unset AOC_OFFLINE to fetch it again",
+                path.display()
+            ),
+            AocError::Io(error) => write!(f, "{error}"),
+            AocError::Dotenv { path, error } => write!(f, "{}: {error}", path.display()),
+            AocError::Config(message) => write!(f, "{message}"),
+            AocError::InvalidPart(part) => {
+                write!(f, "part should be either 1 or 2 but was {part}")
+            }
+            AocError::InvalidLedger { path, reason } => write!(
+                f,
+                "the answer ledger {} is invalid ({reason}). Fix or delete it",
+                path.display()
+            ),
+            AocError::InvalidInput { line, reason } => {
+                write!(f, "line {line} of the input is invalid: {reason}")
+            }
+            AocError::Refused(refusal) => write!(f, "refusing to submit: {refusal}"),
+            AocError::UnexpectedResponse(message) => {
+                write!(f, "unrecognized response from AoC: {message}")
+            }
+            AocError::Git(error) => write!(f, "{error}"),
+            AocError::Leaking(leaks) => {
+                write!(
+                    f,
+                    "refusing to continue because AoC asks that inputs are not published:"
+                )?;
+                for leak in leaks {
+                    write!(f, "\n  - {leak}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Network(error) => Some(error),
+            AocError::Io(error) => Some(error),
+            AocError::Dotenv { error, .. } => Some(error),
+            AocError::Refused(refusal) => Some(refusal),
+            AocError::Git(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(error: std::io::Error) -> Self {
+        AocError::Io(error)
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(error: reqwest::Error) -> Self {
+        AocError::Network(error)
+    }
+}
+
+impl From<git2::Error> for AocError {
+    fn from(error: git2::Error) -> Self {
+        AocError::Git(error)
+    }
+}
+
+impl From<Refusal> for AocError {
+    fn from(refusal: Refusal) -> Self {
+        AocError::Refused(refusal)
+    }
+}
@@ -0,0 +1,158 @@
+use super::AocError;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use std::fmt;
+
+/// a year of Advent of Code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Event {
+    year: u16,
+}
+
+impl Event {
+    pub const FIRST_YEAR: u16 = 2015;
+
+    pub fn new(year: u16) -> Result<Self, AocError> {
+        match year >= Self::FIRST_YEAR {
+            true => Ok(Event { year }),
+            false => Err(AocError::NoSuchEvent(year)),
+        }
+    }
+
+    /// validates a year given on the command line
+    pub fn parse(year: &str) -> Result<Self, AocError> {
+        Event::new(year.trim().parse().map_err(|_| {
+            AocError::Config(format!("the year should be a number but was '{year}'"))
+        })?)
+    }
+
+    /// the most recent event which has started (Ex: 2024 until December 2025)
+    pub fn latest(now: DateTime<Utc>) -> Self {
+        let event = Event {
+            year: now.year() as u16,
+        };
+        match now >= event.first_unlock() {
+            true => event,
+            false => Event {
+                year: event.year - 1,
+            },
+        }
+    }
+
+    /// every event which has started, oldest first
+    pub fn all(now: DateTime<Utc>) -> impl Iterator<Item = Event> {
+        (Self::FIRST_YEAR..=Event::latest(now).year).map(|year| Event { year })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn days(&self) -> u8 {
+        match self.year {
+            // the calendar was shortened to 12 days from 2025 onwards
+            ..2025 => 25,
+            _ => 12,
+        }
+    }
+
+    pub fn day(&self, day: u8) -> Result<Day, AocError> {
+        match (1..=self.days()).contains(&day) {
+            true => Ok(Day { event: *self, day }),
+            false => Err(AocError::NoSuchDay {
+                year: self.year,
+                day,
+            }),
+        }
+    }
+
+    /// every day of the event in order
+    pub fn all_days(&self) -> impl Iterator<Item = Day> {
+        let event = *self;
+        (1..=self.days()).map(move |day| Day { event, day })
+    }
+
+    /// the days which have unlocked by `now`, in order
+    pub fn unlocked_days(&self, now: DateTime<Utc>) -> impl Iterator<Item = Day> {
+        self.all_days().filter(move |day| day.is_unlocked(now))
+    }
+
+    fn first_unlock(&self) -> DateTime<Utc> {
+        Day {
+            event: *self,
+            day: 1,
+        }
+        .unlock_time()
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.year)
+    }
+}
+
+/// a single day of an [`Event`], which is always valid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day {
+    event: Event,
+    day: u8,
+}
+
+impl Day {
+    /// validates a year and day given on the command line
+    pub fn parse(year: &str, day: &str) -> Result<Self, AocError> {
+        Event::parse(year)?.day(
+            day.trim().parse().map_err(|_| {
+                AocError::Config(format!("the day should be a number but was '{day}'"))
+            })?,
+        )
+    }
+
+    pub fn event(&self) -> Event {
+        self.event
+    }
+
+    pub fn year(&self) -> u16 {
+        self.event.year
+    }
+
+    pub fn number(&self) -> u8 {
+        self.day
+    }
+
+    /// the last day only has one puzzle, its second star is awarded for
+    /// collecting every other star
+    pub fn parts(&self) -> u8 {
+        match self.day == self.event.days() {
+            true => 1,
+            false => 2,
+        }
+    }
+
+    /// puzzles unlock at midnight US Eastern time which is always EST (UTC-5) in December
+    pub fn unlock_time(&self) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(self.year().into(), 12, self.day.into(), 5, 0, 0)
+            .single()
+            .expect("every day of December should exist")
+    }
+
+    pub fn is_unlocked(&self, now: DateTime<Utc>) -> bool {
+        now >= self.unlock_time()
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} of {}", self.day, self.event.year)
+    }
+}
+
+/// the number of days in an event, or `None` if there was no event that year
+pub fn days_in_event(year: u16) -> Option<u8> {
+    Some(Event::new(year).ok()?.days())
+}
+
+/// when a puzzle unlocks, see [`Day::unlock_time`]
+pub fn unlock_time(year: u16, day: u8) -> Result<DateTime<Utc>, AocError> {
+    Ok(Event::new(year)?.day(day)?.unlock_time())
+}
@@ -0,0 +1,135 @@
+use super::{AocError, Client};
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::Method;
+use reqwest::blocking::RequestBuilder;
+use reqwest::header::HeaderMap;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+/// identifies these tools to AoC, as requested for automated access, unless
+/// `AOC_USER_AGENT` is set
+pub(super) const DEFAULT_USER_AGENT: &str = "github.com/jarpoole/advent_of_code";
+
+/// the default for `AOC_MIN_INTERVAL`
+pub(super) const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// how many times a GET request which failed with a 5xx status is retried.
+/// Anything else (Ex: submitting an answer) may have been acted on by AoC
+/// despite the error so it is never sent twice
+const RETRIES: u32 = 3;
+
+/// the client shared by every request a [`Client`] sends. Redirects are not
+/// followed because AoC only redirects to the login page
+pub(super) fn http_client(user_agent: &str) -> Result<reqwest::blocking::Client, AocError> {
+    Ok(reqwest::blocking::Client::builder()
+        .user_agent(user_agent)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?)
+}
+
+/// pages are only ever rendered with a login link when the session cookie
+/// was not accepted
+fn is_logged_out(body: &str) -> bool {
+    body.contains("/auth/login\"")
+}
+
+impl Client {
+    /// sends a request to AoC and returns the status and body of the response.
+    /// Waits until at least the minimum interval has passed since the last
+    /// request from any process and retries server errors of GET requests with
+    /// exponential backoff
+    pub(super) fn send(&self, request: RequestBuilder) -> Result<(u16, String), AocError> {
+        let (status, _, body) = self.send_with_headers(request)?;
+        Ok((status, body))
+    }
+
+    /// like [`Client::send`] but also returns the headers of the response
+    pub(super) fn send_with_headers(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(u16, HeaderMap, String), AocError> {
+        let request = request.build()?;
+        let max_retries = match request.method() == Method::GET {
+            true => RETRIES,
+            false => 0,
+        };
+        let mut backoff = Duration::from_secs(1);
+        let mut retries = 0;
+        loop {
+            self.throttle()?;
+            let response = self.http.execute(
+                request
+                    .try_clone()
+                    .expect("requests without streamed bodies should always be clonable"),
+            )?;
+            let status = response.status();
+            if status.is_server_error() && retries < max_retries {
+                eprintln!(
+                    "{} responded with HTTP {}, retrying in {}s",
+                    response.url(),
+                    status.as_u16(),
+                    backoff.as_secs()
+                );
+                retries += 1;
+                self.clock.sleep(backoff);
+                backoff *= 2;
+                continue;
+            }
+            if status.is_redirection()
+                && response
+                    .headers()
+                    .get(reqwest::header::LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .is_some_and(|location| location.contains("/auth/login"))
+            {
+                return Err(self.session_expired());
+            }
+            let headers = response.headers().clone();
+            let body = response.text()?;
+            if status.is_success() && is_logged_out(&body) {
+                return Err(self.session_expired());
+            }
+            return Ok((status.as_u16(), headers, body));
+        }
+    }
+
+    /// sleeps until the minimum interval has passed since the last request.
+    /// The time of the last request is kept in a file so that it applies to
+    /// every process (and profile) which shares the cache
+    fn throttle(&self) -> Result<(), AocError> {
+        if self.min_interval.is_zero() {
+            return Ok(());
+        }
+        if let Some(parent) = self.throttle_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.throttle_file)?;
+        // other processes wait here until this one has sent its request
+        file.lock()?;
+        let mut last_request = String::new();
+        file.read_to_string(&mut last_request)?;
+        if let Ok(last_request) = DateTime::parse_from_rfc3339(last_request.trim()) {
+            let next_request = last_request.with_timezone(&Utc)
+                + TimeDelta::from_std(self.min_interval)
+                    .expect("the minimum interval should have been validated by Client::from_vars");
+            let now = self.clock.now();
+            if next_request > now {
+                self.clock.sleep(
+                    (next_request - now)
+                        .to_std()
+                        .expect("the time until the next request should be positive"),
+                );
+            }
+        }
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(self.clock.now().to_rfc3339().as_bytes())?;
+        Ok(())
+    }
+}
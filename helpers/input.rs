@@ -0,0 +1,147 @@
+use super::AocError;
+use ndarray::Array2;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// a puzzle input (or example) with consistent line endings: `\n` only and
+/// exactly one at the end. Dereferences to the text for days which parse it
+/// themselves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(text: &str) -> Self {
+        let mut text = text.replace("\r\n", "\n");
+        text.truncate(text.trim_end_matches('\n').len());
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        Input { text }
+    }
+
+    /// an example written as an indented raw string in a test. Fixtures which
+    /// start with a newline have their common indentation removed, Ex:
+    ///
+    /// ```text
+    /// static EXAMPLE_INPUT: &str = r#"
+    ///     L68
+    ///     L30
+    /// "#;
+    /// ```
+    pub fn example(fixture: &str) -> Self {
+        let Some(block) = fixture
+            .strip_prefix('\n')
+            .or_else(|| fixture.strip_prefix("\r\n"))
+        else {
+            return Input::new(fixture);
+        };
+        let indent = block
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let dedented = block
+            .lines()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Input::new(&dedented)
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// groups of lines separated by blank lines
+    pub fn paragraphs(&self) -> impl Iterator<Item = Input> {
+        self.text
+            .split("\n\n")
+            .filter(|paragraph| !paragraph.trim().is_empty())
+            .map(Input::new)
+    }
+
+    /// parses every line as a `T`, reporting the first line which is invalid
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>, AocError>
+    where
+        T::Err: fmt::Display,
+    {
+        self.lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse::<T>().map_err(|error| AocError::InvalidInput {
+                    line: index + 1,
+                    reason: format!("'{line}' {error}"),
+                })
+            })
+            .collect()
+    }
+
+    /// converts every character to a `T` in a grid indexed by `[row, column]`.
+    /// Every line must be the same length
+    pub fn grid<T: TryFrom<char>>(&self) -> Result<Array2<T>, AocError> {
+        let columns = self.lines().next().unwrap_or_default().chars().count();
+        let mut rows = 0;
+        let mut cells = Vec::new();
+        for (index, line) in self.lines().enumerate() {
+            let invalid = |reason: String| AocError::InvalidInput {
+                line: index + 1,
+                reason,
+            };
+            if line.chars().count() != columns {
+                return Err(invalid(format!(
+                    "expected {columns} columns but found {}",
+                    line.chars().count()
+                )));
+            }
+            for char in line.chars() {
+                cells.push(
+                    T::try_from(char)
+                        .map_err(|_| invalid(format!("'{char}' is not a valid cell")))?,
+                );
+            }
+            rows += 1;
+        }
+        Ok(Array2::from_shape_vec((rows, columns), cells)
+            .expect("every row should have the same number of columns"))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input::new(text)
+    }
+}
+
+impl PartialEq<&str> for Input {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
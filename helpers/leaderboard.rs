@@ -0,0 +1,178 @@
+use super::{AocError, Client, Event, Page};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// AoC asks that private leaderboards are fetched at most once every 15 minutes
+pub const LEADERBOARD_REFRESH: TimeDelta = TimeDelta::minutes(15);
+
+/// the JSON export of a private leaderboard
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    /// keyed by the member's id
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub last_star_ts: i64,
+    /// the stars earned on each day, keyed by day and then by part
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Star {
+    /// when the star was earned as a Unix timestamp
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+impl Member {
+    /// how AoC shows the member (Ex: (anonymous user #12345))
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// when the member earned the star for a part of a day
+    pub fn star_time(&self, day: u8, part: u8) -> Option<DateTime<Utc>> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        DateTime::from_timestamp(star.get_star_ts, 0)
+    }
+}
+
+impl Leaderboard {
+    /// the members ordered by local score, breaking ties in favour of whoever
+    /// earned their last star first like AoC does
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+        members
+    }
+}
+
+pub fn parse_leaderboard(json: &str) -> Result<Leaderboard, AocError> {
+    serde_json::from_str(json).map_err(|error| {
+        AocError::UnexpectedResponse(format!("the leaderboard could not be parsed ({error})"))
+    })
+}
+
+/// a private leaderboard, fetched at most once every [`LEADERBOARD_REFRESH`]
+pub fn get_leaderboard(year: u16, id: u64) -> Result<Leaderboard, AocError> {
+    Client::from_env()?.get_leaderboard(year, id)
+}
+
+impl Client {
+    pub fn get_leaderboard(&self, year: u16, id: u64) -> Result<Leaderboard, AocError> {
+        let page = Page::Leaderboard { year, id };
+        let fresh = self.cached_at(&page).is_some_and(|fetched| {
+            self.offline || self.clock.now() - fetched < LEADERBOARD_REFRESH
+        });
+        let json = match (fresh, self.read_cache(&page)) {
+            (true, Some(json)) => json,
+            _ => self.fetch_page(&page)?,
+        };
+        parse_leaderboard(&json)
+    }
+}
+
+/// formats a duration as hours, minutes and seconds (Ex: 26:03:09)
+pub fn format_duration(duration: TimeDelta) -> String {
+    let seconds = duration.num_seconds();
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// a table of every member's score and stars, followed by how long each star
+/// took: part 1 since the puzzle unlocked and part 2 since part 1
+pub fn render_leaderboard(event: Event, leaderboard: &Leaderboard) -> String {
+    let ranking = leaderboard.ranking();
+    let year = event.year();
+    let name_width = ranking
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Name".len());
+
+    let owner = leaderboard
+        .members
+        .get(&leaderboard.owner_id.to_string())
+        .map(Member::display_name)
+        .unwrap_or(format!("#{}", leaderboard.owner_id));
+    let mut table = format!("Private leaderboard of {owner} for {year}\n\n");
+    table.push_str(&format!(
+        "{:>4}  {:<name_width$}  {:>5}  {:>5} ",
+        "Rank", "Name", "Score", "Stars"
+    ));
+    for day in event.all_days() {
+        table.push_str(&format!(" {:>2}", day.number()));
+    }
+    table.push('\n');
+    for (rank, member) in ranking.iter().enumerate() {
+        table.push_str(&format!(
+            "{:>4}  {:<name_width$}  {:>5}  {:>5} ",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars
+        ));
+        for day in event.all_days().map(|day| day.number()) {
+            let glyph = match (member.star_time(day, 1), member.star_time(day, 2)) {
+                (Some(_), Some(_)) => '★',
+                (Some(_), None) => '☆',
+                _ => '·',
+            };
+            table.push_str(&format!("  {glyph}"));
+        }
+        table.push('\n');
+    }
+
+    table.push_str(&format!(
+        "\nCompletion times (part 1 since unlock, part 2 since part 1)\n\n{:<name_width$}  {:>3}  {:>10}  {:>10}\n",
+        "Name", "Day", "Part 1", "Part 2"
+    ));
+    for member in &ranking {
+        for day in event.all_days() {
+            let (unlock, day) = (day.unlock_time(), day.number());
+            let Some(part1) = member.star_time(day, 1) else {
+                continue;
+            };
+            let part2 = member
+                .star_time(day, 2)
+                .map(|part2| format!("+{}", format_duration(part2 - part1)))
+                .unwrap_or_default();
+            let row = format!(
+                "{:<name_width$}  {day:>3}  {:>10}  {part2:>10}",
+                member.display_name(),
+                format_duration(part1 - unlock),
+            );
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+    }
+    table
+}
@@ -0,0 +1,176 @@
+use super::{AocError, Client, Input};
+use git2::{ObjectType, Oid, Repository};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// a way that an input or the session cookie could be committed. Paths are
+/// relative to the root of the repository
+#[derive(Debug, Clone, PartialEq)]
+pub enum Leak {
+    /// a file in the cache is tracked or staged
+    CachedPage(PathBuf),
+    /// a .env file is tracked or staged
+    Dotenv(PathBuf),
+    /// a tracked or staged file has the same contents as a cached input
+    Input { path: PathBuf, input: PathBuf },
+    /// the cache or .env file would be committed by `git add -A`
+    NotIgnored(PathBuf),
+}
+
+impl fmt::Display for Leak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Leak::CachedPage(path) => write!(
+                f,
+                "{} is in the cache but tracked by git, untrack it with `git rm --cached`",
+                path.display()
+            ),
+            Leak::Dotenv(path) => write!(
+                f,
+                "{} contains the session cookie but is tracked by git, untrack it with `git rm --cached`",
+                path.display()
+            ),
+            Leak::Input { path, input } => write!(
+                f,
+                "{} is tracked by git but is the same as the input cached in {}",
+                path.display(),
+                input.display()
+            ),
+            Leak::NotIgnored(path) => write!(f, "{} is not ignored by git", path.display()),
+        }
+    }
+}
+
+/// the path relative to the root of the repository, or `None` if it is outside
+/// of the repository
+fn repo_relative_path(workdir: &Path, path: &Path) -> Result<Option<PathBuf>, AocError> {
+    let path = match path.is_absolute() {
+        true => path.to_owned(),
+        false => std::env::current_dir()?.join(path),
+    };
+    // the workdir is canonical so the path has to be too, as far as it exists
+    let path = path.canonicalize().unwrap_or(path);
+    Ok(path
+        .strip_prefix(workdir.canonicalize()?)
+        .ok()
+        .map(Path::to_owned))
+}
+
+/// every cached input (of any profile) under the ids git would store them
+/// as, including the normalized versions which are more likely to be copied
+/// around
+fn cached_inputs(cache_root: &Path) -> Result<HashMap<Oid, PathBuf>, AocError> {
+    let mut inputs = HashMap::new();
+    let mut directories = vec![cache_root.to_owned()];
+    while let Some(directory) = directories.pop() {
+        let Ok(children) = fs::read_dir(&directory) else {
+            continue;
+        };
+        for child in children {
+            let path = child?.path();
+            if path.is_dir() {
+                directories.push(path);
+                continue;
+            }
+            if path.file_name().is_none_or(|name| name != "input.txt") {
+                continue;
+            }
+            let raw = fs::read_to_string(&path)?;
+            let normalized = Input::new(&raw);
+            for data in [raw.as_str(), normalized.raw(), normalized.trim_end()] {
+                inputs.insert(
+                    Oid::hash_object(ObjectType::Blob, data.as_bytes())?,
+                    path.clone(),
+                );
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+impl Client {
+    /// everything which could lead to an input or the session cookie being
+    /// published along with the repository
+    pub fn find_leaks(&self, repo: &Repository) -> Result<Vec<Leak>, AocError> {
+        let Some(workdir) = repo.workdir() else {
+            return Ok(Vec::new());
+        };
+        let cache = repo_relative_path(workdir, &self.cache_root)?
+            // a cache in the root of the repository cannot be told apart from the code
+            .filter(|cache| !cache.as_os_str().is_empty());
+        let inputs = cached_inputs(&self.cache_root)?;
+        let mut leaks = Vec::new();
+        for entry in repo.index()?.iter() {
+            let path = PathBuf::from(String::from_utf8_lossy(&entry.path).into_owned());
+            if cache.as_ref().is_some_and(|cache| path.starts_with(cache)) {
+                leaks.push(Leak::CachedPage(path));
+            } else if path.file_name().is_some_and(|name| name == ".env") {
+                leaks.push(Leak::Dotenv(path));
+            } else if let Some(input) = inputs.get(&entry.id) {
+                leaks.push(Leak::Input {
+                    path,
+                    input: input.clone(),
+                });
+            }
+        }
+        // a directory is ignored if anything inside of it is
+        if let Some(cache) = cache
+            && !repo.is_path_ignored(cache.join("last-request"))?
+        {
+            leaks.push(Leak::NotIgnored(cache));
+        }
+        if workdir.join(".env").exists() && !repo.is_path_ignored(".env")? {
+            leaks.push(Leak::NotIgnored(PathBuf::from(".env")));
+        }
+        Ok(leaks)
+    }
+}
+
+/// the .gitignore rules which stop the cache and .env files from being
+/// committed again (Ex: /cache/)
+pub fn ignore_rules(leaks: &[Leak]) -> Vec<String> {
+    let mut rules = Vec::new();
+    for leak in leaks {
+        let rule = match leak {
+            Leak::Dotenv(_) => ".env".to_owned(),
+            Leak::NotIgnored(path) if path == Path::new(".env") => ".env".to_owned(),
+            Leak::NotIgnored(path) => format!("/{}/", path.display()),
+            Leak::CachedPage(_) | Leak::Input { .. } => continue,
+        };
+        if !rules.contains(&rule) {
+            rules.push(rule);
+        }
+    }
+    rules
+}
+
+/// appends rules to the .gitignore at the root of the repository, skipping
+/// any which are already there
+pub fn add_ignore_rules(repo: &Repository, rules: &[String]) -> Result<(), AocError> {
+    let path = repo
+        .workdir()
+        .ok_or(AocError::Config(
+            "a bare repository cannot ignore files".to_owned(),
+        ))?
+        .join(".gitignore");
+    let mut gitignore = fs::read_to_string(&path).unwrap_or_default();
+    if !gitignore.is_empty() && !gitignore.ends_with('\n') {
+        gitignore.push('\n');
+    }
+    let rules = rules
+        .iter()
+        .filter(|rule| !gitignore.lines().any(|line| line.trim() == rule.as_str()))
+        .collect::<Vec<_>>();
+    if rules.is_empty() {
+        return Ok(());
+    }
+    gitignore.push_str("# AoC asks that inputs are not published\n");
+    for rule in rules {
+        gitignore.push_str(rule);
+        gitignore.push('\n');
+    }
+    fs::write(path, gitignore)?;
+    Ok(())
+}
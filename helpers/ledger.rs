@@ -0,0 +1,230 @@
+use super::{AocError, Verdict};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, Value};
+
+/// a single answer that was previously sent to AoC
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+    pub submitted: DateTime<Utc>,
+}
+
+/// the reason an answer was not sent to AoC
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refusal {
+    /// the part was already solved, with this answer if it was submitted here
+    AlreadySolved(Option<String>),
+    /// the exact same answer was already rejected
+    KnownWrong(Verdict),
+    /// a smaller (or equal) answer was already too high
+    TooHigh(i128),
+    /// a larger (or equal) answer was already too low
+    TooLow(i128),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(Some(answer)) => {
+                write!(f, "part was already solved with the answer {answer}")
+            }
+            Refusal::AlreadySolved(None) => write!(f, "part was already solved"),
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "answer was already submitted and was {verdict}")
+            }
+            Refusal::TooHigh(bound) => {
+                write!(f, "answer must be less than {bound} which was too high")
+            }
+            Refusal::TooLow(bound) => {
+                write!(f, "answer must be greater than {bound} which was too low")
+            }
+        }
+    }
+}
+
+impl Error for Refusal {}
+
+/// inverse of the [`fmt::Display`] implementation for [`Verdict`]. The wait
+/// time of rate limits is not recorded because it is meaningless after the fact
+fn parse_verdict_name(verdict: &str) -> Option<Verdict> {
+    match verdict {
+        "correct" => Some(Verdict::Correct),
+        "too high" => Some(Verdict::TooHigh),
+        "too low" => Some(Verdict::TooLow),
+        "incorrect" => Some(Verdict::Incorrect),
+        "already solved" => Some(Verdict::AlreadySolved),
+        "rate limited" => Some(Verdict::RateLimited(Default::default())),
+        _ => None,
+    }
+}
+
+/// every answer submitted for a single day, persisted as TOML so that answers
+/// which are known to be wrong never reach AoC (and trigger a lockout) again
+pub struct Ledger {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl Ledger {
+    /// the ledger lives next to the code for each day (Ex: 2025/5/answers.toml
+    /// in the `root` of the repository) and every profile has its own since
+    /// answers differ between accounts (Ex: 2025/5/answers.alice.toml)
+    pub fn path(root: &Path, year: u16, day: u8, profile: Option<&str>) -> PathBuf {
+        let directory = root.join(year.to_string()).join(day.to_string());
+        match profile {
+            Some(profile) => directory.join(format!("answers.{profile}.toml")),
+            None => directory.join("answers.toml"),
+        }
+    }
+
+    /// reads the ledger at `path` or starts an empty one if it does not exist yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref().to_owned();
+        let document = match fs::read_to_string(&path) {
+            Ok(content) => {
+                content
+                    .parse::<DocumentMut>()
+                    .map_err(|error| AocError::InvalidLedger {
+                        path: path.clone(),
+                        reason: error.to_string(),
+                    })?
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => DocumentMut::new(),
+            Err(error) => return Err(error.into()),
+        };
+        Ok(Ledger { path, document })
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, self.document.to_string())?;
+        Ok(())
+    }
+
+    /// all submissions for a part in the order they were made
+    pub fn submissions(&self, part: u8) -> Result<Vec<Submission>, AocError> {
+        let invalid = |reason: String| AocError::InvalidLedger {
+            path: self.path.clone(),
+            reason,
+        };
+        let Some(tables) = self.document.get(&format!("part{part}")) else {
+            return Ok(Vec::new());
+        };
+        let tables = tables
+            .as_array_of_tables()
+            .ok_or(invalid(format!("part{part} should be an array of tables")))?;
+        tables
+            .iter()
+            .map(|table| {
+                let field = |name: &str| {
+                    table
+                        .get(name)
+                        .and_then(|item| item.as_value())
+                        .ok_or(format!("submission is missing '{name}'"))
+                };
+                let answer = field("answer")?
+                    .as_str()
+                    .ok_or("answer should be a string")?
+                    .to_owned();
+                let verdict = field("verdict")?
+                    .as_str()
+                    .and_then(parse_verdict_name)
+                    .ok_or("verdict should be a known verdict")?;
+                let submitted = field("submitted")?
+                    .as_datetime()
+                    .ok_or("submitted should be a datetime")?;
+                Ok(Submission {
+                    answer,
+                    verdict,
+                    submitted: DateTime::parse_from_rfc3339(&submitted.to_string())
+                        .map_err(|error| error.to_string())?
+                        .into(),
+                })
+            })
+            .collect::<Result<_, String>>()
+            .map_err(invalid)
+    }
+
+    /// the exclusive range that the answer to a part must fall within based
+    /// on previous "too high" and "too low" verdicts
+    pub fn bounds(&self, part: u8) -> Result<(Option<i128>, Option<i128>), AocError> {
+        let submissions = self.submissions(part)?;
+        let numeric_answers = |verdict: Verdict| {
+            submissions
+                .iter()
+                .filter(move |submission| submission.verdict == verdict)
+                .filter_map(|submission| submission.answer.parse::<i128>().ok())
+        };
+        Ok((
+            numeric_answers(Verdict::TooLow).max(),
+            numeric_answers(Verdict::TooHigh).min(),
+        ))
+    }
+
+    /// checks an answer against everything that is already known about a part
+    /// before it is submitted
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), AocError> {
+        let submissions = self.submissions(part)?;
+        if let Some(correct) = submissions
+            .iter()
+            .find(|submission| submission.verdict == Verdict::Correct)
+        {
+            return Err(Refusal::AlreadySolved(Some(correct.answer.clone())).into());
+        }
+        // AoC said so without saying which answer was right (Ex: it was solved
+        // on the website)
+        if submissions
+            .iter()
+            .any(|submission| submission.verdict == Verdict::AlreadySolved)
+        {
+            return Err(Refusal::AlreadySolved(None).into());
+        }
+        if let Some(wrong) = submissions.iter().find(|submission| {
+            submission.answer == answer
+                && matches!(
+                    submission.verdict,
+                    Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+                )
+        }) {
+            return Err(Refusal::KnownWrong(wrong.verdict).into());
+        }
+        if let Ok(answer) = answer.parse::<i128>() {
+            let (lower, upper) = self.bounds(part)?;
+            if let Some(lower) = lower
+                && answer <= lower
+            {
+                return Err(Refusal::TooLow(lower).into());
+            }
+            if let Some(upper) = upper
+                && answer >= upper
+            {
+                return Err(Refusal::TooHigh(upper).into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict, submitted: DateTime<Utc>) {
+        let submitted = submitted
+            .to_rfc3339_opts(SecondsFormat::Secs, true)
+            .parse::<toml_edit::Datetime>()
+            .expect("RFC 3339 timestamps should always be valid TOML datetimes");
+        let mut table = Table::new();
+        table["answer"] = Item::Value(answer.into());
+        table["verdict"] = Item::Value(verdict.to_string().into());
+        table["submitted"] = Item::Value(Value::from(submitted));
+        self.document
+            .entry(&format!("part{part}"))
+            .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+            .as_array_of_tables_mut()
+            .expect("part should always be an array of tables")
+            .push(table);
+    }
+}
@@ -0,0 +1,50 @@
+use super::{AocError, Client, Page};
+use std::fmt;
+
+/// what happened to a day while prefetching a year
+#[derive(Debug, Clone, PartialEq)]
+pub enum Prefetched {
+    Fetched,
+    /// the input and puzzle were both already cached so nothing was sent
+    Cached,
+    Locked,
+    /// AoC refused to serve the day to this session (Ex: HTTP 400)
+    Unavailable(String),
+}
+
+impl fmt::Display for Prefetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Prefetched::Fetched => write!(f, "fetched"),
+            Prefetched::Cached => write!(f, "already cached"),
+            Prefetched::Locked => write!(f, "not unlocked yet"),
+            Prefetched::Unavailable(reason) => write!(f, "unavailable ({reason})"),
+        }
+    }
+}
+
+impl Client {
+    /// caches the input and puzzle of a day unless they already are, so that
+    /// the day can be solved offline later. Problems with the day itself are
+    /// reported while anything which would affect every day is an error
+    pub fn prefetch_day(&self, year: u16, day: u8) -> Result<Prefetched, AocError> {
+        let missing = [Page::Input { year, day }, Page::Puzzle { year, day }]
+            .into_iter()
+            .filter(|page| self.read_cache(page).is_none())
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return Ok(Prefetched::Cached);
+        }
+        for page in &missing {
+            match self.fetch_page(page) {
+                Ok(_) => {}
+                Err(AocError::PuzzleNotUnlocked { .. }) => return Ok(Prefetched::Locked),
+                Err(error @ AocError::Http { .. }) => {
+                    return Ok(Prefetched::Unavailable(error.to_string()));
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(Prefetched::Fetched)
+    }
+}
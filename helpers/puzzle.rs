@@ -0,0 +1,292 @@
+use super::{AocError, Client, Event, Ledger, Page, Verdict};
+use scraper::{ElementRef, Html, Node, Selector};
+use std::fs;
+use std::path::Path;
+
+/// downloads the description of a puzzle, converts it to markdown, and saves
+/// it next to the code for the day (Ex: ./2025/5/puzzle.md)
+pub fn get_puzzle(year: u16, day: u8, wait: bool) -> Result<String, AocError> {
+    let client = Client::from_env()?.wait_for_unlock(wait);
+    let html = client.get_puzzle_page(year, day)?;
+    save_puzzle(client.root(), year, day, &html)
+}
+
+/// the raw HTML page describing a puzzle, waiting for it to unlock when
+/// `wait` is set
+pub fn get_puzzle_page(year: u16, day: u8, wait: bool) -> Result<String, AocError> {
+    Client::from_env()?
+        .wait_for_unlock(wait)
+        .get_puzzle_page(year, day)
+}
+
+impl Client {
+    pub fn get_puzzle_page(&self, year: u16, day: u8) -> Result<String, AocError> {
+        let page = Page::Puzzle { year, day };
+        let html = match self.read_cache(&page) {
+            Some(html)
+                if !self.offline && self.part2_unlocked_since_cached(year, day, &html)? =>
+            {
+                self.fetch_page(&page)?
+            }
+            Some(html) => html,
+            None => self.fetch_page(&page)?,
+        };
+        self.record_opened(year, day);
+        Ok(html)
+    }
+
+    /// downloads the puzzle page again even if it is cached (Ex: once part 2
+    /// was unlocked by solving part 1 on the website)
+    pub fn refresh_puzzle_page(&self, year: u16, day: u8) -> Result<String, AocError> {
+        if self.offline {
+            return self.get_puzzle_page(year, day);
+        }
+        let html = self.fetch_page(&Page::Puzzle { year, day })?;
+        self.record_opened(year, day);
+        Ok(html)
+    }
+
+    /// the cached page only includes part 2 if it was unlocked at the time the
+    /// page was downloaded, and part 2 is unlocked by solving part 1. The last
+    /// day never has a second part to wait for
+    fn part2_unlocked_since_cached(
+        &self,
+        year: u16,
+        day: u8,
+        html: &str,
+    ) -> Result<bool, AocError> {
+        if count_parts(html) >= 2 || Event::new(year)?.day(day)?.parts() < 2 {
+            return Ok(false);
+        }
+        let ledger = Ledger::load(Ledger::path(self.root(), year, day, self.profile()))?;
+        let solved = ledger
+            .submissions(1)?
+            .into_iter()
+            .filter(|submission| {
+                matches!(
+                    submission.verdict,
+                    Verdict::Correct | Verdict::AlreadySolved
+                )
+            })
+            .map(|submission| submission.submitted)
+            .max();
+        Ok(
+            match (solved, self.cached_at(&Page::Puzzle { year, day })) {
+                (Some(solved), Some(cached)) => solved >= cached,
+                (Some(_), None) => true,
+                (None, _) => false,
+            },
+        )
+    }
+}
+
+/// writes the markdown version of a puzzle page next to the code for the day,
+/// in the repository at `root`
+pub fn save_puzzle(root: &Path, year: u16, day: u8, html: &str) -> Result<String, AocError> {
+    let markdown = format!(
+        "<!-- {}/{}/day/{} -->\n\n{}",
+        super::AOC_URL,
+        year,
+        day,
+        puzzle_to_markdown(html)
+    );
+    let path = root.join(year.to_string()).join(day.to_string());
+    fs::create_dir_all(&path)?;
+    fs::write(path.join("puzzle.md"), &markdown)?;
+    Ok(markdown)
+}
+
+fn day_desc_selector() -> Selector {
+    Selector::parse("article.day-desc").expect("selector should always be valid")
+}
+
+/// the number of parts whose description is included in a puzzle page
+pub fn count_parts(html: &str) -> usize {
+    Html::parse_document(html)
+        .select(&day_desc_selector())
+        .count()
+}
+
+/// picks the code block from the part 1 description that is most likely to be
+/// the example input rather than an intermediate state or some example output
+pub fn find_example_input(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let part1 = document.select(&day_desc_selector()).next()?;
+    let code_blocks = Selector::parse("pre > code").expect("selector should always be valid");
+    part1
+        .select(&code_blocks)
+        .enumerate()
+        .max_by_key(|(index, code)| {
+            // the example input is almost always introduced by a paragraph
+            // like "For example, suppose you have the following list:"
+            let introduction = code
+                .parent()
+                .and_then(|pre| pre.prev_siblings().find_map(ElementRef::wrap))
+                .map(|paragraph| paragraph.text().collect::<String>().to_lowercase())
+                .unwrap_or_default();
+            let mut score = 0;
+            if introduction.contains("example") {
+                score += 2;
+            }
+            if introduction.trim_end().ends_with(':') {
+                score += 1;
+            }
+            // highlighted blocks usually explain the example rather than being it
+            if code.child_elements().next().is_some() {
+                score -= 1;
+            }
+            // "max_by_key" returns the last element in case of ties and the
+            // first one is preferred
+            (score, std::cmp::Reverse(*index))
+        })
+        .map(|(_, code)| code.text().collect::<String>())
+}
+
+/// the answer to the example of each unlocked part, which is usually the last
+/// highlighted code in the description of the part
+pub fn find_example_answers(html: &str) -> Vec<Option<String>> {
+    let document = Html::parse_document(html);
+    let highlighted_code = Selector::parse(":not(pre) > code > em, em > code")
+        .expect("selector should always be valid");
+    document
+        .select(&day_desc_selector())
+        .map(|part| {
+            part.select(&highlighted_code)
+                .last()
+                .map(|answer| answer.text().collect::<String>().trim().to_owned())
+        })
+        .collect()
+}
+
+/// the title of a puzzle without the dashes around it (Ex: Day 3: Snow Counting)
+pub fn find_title(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let heading =
+        Selector::parse("article.day-desc > h2").expect("selector should always be valid");
+    let title = document.select(&heading).next()?.text().collect::<String>();
+    let title = title.trim().trim_matches('-').trim();
+    match title.is_empty() {
+        true => None,
+        false => Some(title.to_owned()),
+    }
+}
+
+/// renders every `<article class="day-desc">` in a puzzle page as markdown
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let document = Html::parse_document(html);
+    let mut markdown = String::new();
+    for article in document.select(&day_desc_selector()) {
+        render_blocks(article, &mut markdown);
+    }
+    markdown.truncate(markdown.trim_end().len());
+    markdown.push('\n');
+    markdown
+}
+
+fn render_blocks(parent: ElementRef, markdown: &mut String) {
+    for child in parent.children() {
+        let Some(element) = ElementRef::wrap(child) else {
+            // stray text between blocks is only ever whitespace in practice
+            if let Node::Text(text) = child.value()
+                && !text.trim().is_empty()
+            {
+                markdown.push_str(&format!("{}\n\n", escape(text).trim()));
+            }
+            continue;
+        };
+        match element.value().name() {
+            "h2" => markdown.push_str(&format!("## {}\n\n", render_inline(element))),
+            "pre" => {
+                let code = element.text().collect::<String>();
+                markdown.push_str(&format!("```\n{}\n```\n\n", code.trim_end_matches('\n')));
+            }
+            "ul" | "ol" => {
+                let ordered = element.value().name() == "ol";
+                for (index, item) in element.child_elements().enumerate() {
+                    let bullet = match ordered {
+                        true => format!("{}.", index + 1),
+                        false => "-".to_owned(),
+                    };
+                    markdown.push_str(&format!("{bullet} {}\n", render_inline(item)));
+                }
+                markdown.push('\n');
+            }
+            _ => markdown.push_str(&format!("{}\n\n", render_inline(element))),
+        }
+    }
+}
+
+fn render_inline(parent: ElementRef) -> String {
+    render_spans(parent).trim().to_owned()
+}
+
+fn render_spans(parent: ElementRef) -> String {
+    let mut markdown = String::new();
+    for child in parent.children() {
+        match ElementRef::wrap(child) {
+            None => {
+                if let Node::Text(text) = child.value() {
+                    markdown.push_str(&collapse_whitespace(&escape(text)));
+                }
+            }
+            Some(element) => match element.value().name() {
+                "em" => markdown.push_str(&delimit(&render_spans(element), "*")),
+                "code" => {
+                    let code = format!("`{}`", element.text().collect::<String>());
+                    // highlighted code is written as <code><em>...</em></code>
+                    match element.child_elements().any(|e| e.value().name() == "em") {
+                        true => markdown.push_str(&format!("*{code}*")),
+                        false => markdown.push_str(&code),
+                    }
+                }
+                "a" => {
+                    let href = element.attr("href").unwrap_or_default();
+                    let href = match href.starts_with('/') {
+                        true => format!("{}{href}", super::AOC_URL),
+                        false => href.to_owned(),
+                    };
+                    markdown.push_str(&format!("[{}]({href})", render_spans(element).trim()));
+                }
+                "br" => markdown.push_str("  \n"),
+                _ => markdown.push_str(&render_spans(element)),
+            },
+        }
+    }
+    markdown
+}
+
+/// wraps text in a markdown delimiter while keeping surrounding whitespace
+/// outside of it because "*text *" is not valid emphasis
+fn delimit(text: &str, delimiter: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_owned();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{leading}{delimiter}{trimmed}{delimiter}{trailing}")
+}
+
+/// escapes characters in plain text which markdown would treat as formatting
+fn escape(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, char| {
+        if matches!(char, '\\' | '*' | '_' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+        escaped
+    })
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    // keep a single space at either end so that words do not run into
+    // adjacent inline elements
+    if text.starts_with(char::is_whitespace) && !collapsed.is_empty() {
+        collapsed.insert(0, ' ');
+    }
+    if text.ends_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+    collapsed
+}
@@ -0,0 +1,224 @@
+use super::{AocError, Client, Page, format_duration, unlock_time};
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use scraper::{Html, Selector};
+use std::fs;
+use std::path::PathBuf;
+
+/// how a single part went according to AoC. Years without a global
+/// leaderboard only show the time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartStats {
+    /// since the puzzle unlocked, or `None` when it took more than 24 hours
+    /// which is all AoC shows
+    pub time: Option<TimeDelta>,
+    pub rank: Option<u32>,
+    pub score: Option<u32>,
+}
+
+/// a row of the personal stats page along with when the puzzle was first
+/// fetched by these tools
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayStats {
+    pub day: u8,
+    /// `None` until the part is solved
+    pub parts: [Option<PartStats>; 2],
+    pub opened: Option<DateTime<Utc>>,
+}
+
+impl DayStats {
+    /// how long a part actually took since the puzzle was opened, rather than
+    /// since it unlocked
+    pub fn solve_duration(&self, year: u16, part: u8) -> Option<TimeDelta> {
+        let time = self
+            .parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_ref()?
+            .time?;
+        let solved = unlock_time(year, self.day).ok()? + time;
+        // puzzles which were solved before they were fetched here
+        Some(solved - self.opened?).filter(|duration| *duration >= TimeDelta::zero())
+    }
+}
+
+/// parses a time on the personal stats page (Ex: 00:12:31). Times over 24
+/// hours are shown as ">24h"
+fn parse_stats_time(time: &str) -> Result<Option<TimeDelta>, AocError> {
+    if time == ">24h" {
+        return Ok(None);
+    }
+    let fields = time
+        .split(':')
+        .map(|field| field.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>();
+    match fields.as_deref() {
+        Some([hours, minutes, seconds]) => Ok(Some(TimeDelta::seconds(
+            hours * 3600 + minutes * 60 + seconds,
+        ))),
+        _ => Err(AocError::UnexpectedResponse(format!(
+            "'{time}' is not a time on the personal stats page"
+        ))),
+    }
+}
+
+fn parse_part_stats(columns: &[&str]) -> Result<Option<PartStats>, AocError> {
+    let number = |column: Option<&&str>| column.and_then(|column| column.parse().ok());
+    match columns.first() {
+        None | Some(&"-") => Ok(None),
+        Some(time) => Ok(Some(PartStats {
+            time: parse_stats_time(time)?,
+            rank: number(columns.get(1)),
+            score: number(columns.get(2)),
+        })),
+    }
+}
+
+/// the rows of `/{year}/leaderboard/self`, most recent day first like AoC
+/// shows them. Accounts without any stars have no rows
+pub fn parse_personal_stats(html: &str) -> Result<Vec<DayStats>, AocError> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("main article pre").expect("selector should always be valid");
+    let Some(table) = document.select(&selector).next() else {
+        return Ok(Vec::new());
+    };
+    let mut days = Vec::new();
+    for line in table.text().collect::<String>().lines() {
+        let columns = line.split_whitespace().collect::<Vec<_>>();
+        // the header rows do not start with a day
+        let Some(Ok(day)) = columns.first().map(|day| day.parse::<u8>()) else {
+            continue;
+        };
+        let columns = &columns[1..];
+        let per_part = match columns.len() {
+            6 => 3,
+            2 => 1,
+            _ => {
+                return Err(AocError::UnexpectedResponse(format!(
+                    "the personal stats for day {day} could not be parsed: '{}'",
+                    line.trim()
+                )));
+            }
+        };
+        days.push(DayStats {
+            day,
+            parts: [
+                parse_part_stats(&columns[..per_part])?,
+                parse_part_stats(&columns[per_part..])?,
+            ],
+            opened: None,
+        });
+    }
+    Ok(days)
+}
+
+/// the personal stats for a year along with the solve durations which can be
+/// worked out from when each puzzle was opened
+pub fn get_personal_stats(year: u16) -> Result<Vec<DayStats>, AocError> {
+    Client::from_env()?.get_personal_stats(year)
+}
+
+impl Client {
+    /// the stats change with every star so they are always fetched again
+    /// unless running offline
+    pub fn get_personal_stats(&self, year: u16) -> Result<Vec<DayStats>, AocError> {
+        let page = Page::Stats { year };
+        let html = match self.offline {
+            true => self.get_page(&page)?,
+            false => self.fetch_page(&page)?,
+        };
+        let mut days = parse_personal_stats(&html)?;
+        for day in &mut days {
+            day.opened = self.opened_at(year, day.day);
+        }
+        Ok(days)
+    }
+
+    /// where the time a puzzle was first opened is kept (Ex: cache/2025/5/opened.txt)
+    fn opened_filename(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("{year}/{day}/opened.txt"))
+    }
+
+    /// when the input or description of a puzzle was first used, which is when
+    /// solving it started. Prefetching a day does not count as opening it
+    pub fn opened_at(&self, year: u16, day: u8) -> Option<DateTime<Utc>> {
+        let opened = fs::read_to_string(self.opened_filename(year, day)).ok()?;
+        Some(DateTime::parse_from_rfc3339(opened.trim()).ok()?.into())
+    }
+
+    /// remembers that a puzzle was opened now, unless it was opened before.
+    /// Only called when a page is handed out, not whenever one is fetched.
+    /// This is only for the stats so failing to record it is just a warning
+    pub(super) fn record_opened(&self, year: u16, day: u8) {
+        let filename = self.opened_filename(year, day);
+        if filename.exists() {
+            return;
+        }
+        let recorded = filename
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| {
+                fs::write(
+                    &filename,
+                    self.clock.now().to_rfc3339_opts(SecondsFormat::Secs, true),
+                )
+            });
+        if let Err(error) = recorded {
+            eprintln!(
+                "Failed to record when day {day} of {year} was opened in {}: {error}",
+                filename.display()
+            );
+        }
+    }
+}
+
+/// a table of the personal stats for a year with a column for how long each
+/// part took since the puzzle was opened
+pub fn render_personal_stats(year: u16, days: &[DayStats]) -> String {
+    let mut table = format!(
+        "Personal stats for {year} (solved in is since the puzzle was first opened)\n\n{:>3}  {:<20}  {:>8}  {:>6}  {:>5}  {:>9}  {:>8}  {:>6}  {:>5}  {:>9}\n",
+        "Day",
+        "Opened",
+        "Part 1",
+        "Rank",
+        "Score",
+        "Solved in",
+        "Part 2",
+        "Rank",
+        "Score",
+        "Solved in"
+    );
+    let optional = |value: Option<String>| value.unwrap_or("-".to_owned());
+    for day in days {
+        let mut row = format!(
+            "{:>3}  {:<20}",
+            day.day,
+            optional(
+                day.opened
+                    .map(|opened| opened.to_rfc3339_opts(SecondsFormat::Secs, true))
+            )
+        );
+        for part in 1..=2 {
+            let stats = day.parts[usize::from(part) - 1];
+            let time = match stats {
+                Some(PartStats { time: None, .. }) => ">24h".to_owned(),
+                _ => optional(stats.and_then(|stats| stats.time).map(format_duration)),
+            };
+            row.push_str(&format!(
+                "  {time:>8}  {:>6}  {:>5}  {:>9}",
+                optional(
+                    stats
+                        .and_then(|stats| stats.rank)
+                        .map(|rank| rank.to_string())
+                ),
+                optional(
+                    stats
+                        .and_then(|stats| stats.score)
+                        .map(|score| score.to_string())
+                ),
+                optional(day.solve_duration(year, part).map(format_duration)),
+            ));
+        }
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+    table
+}
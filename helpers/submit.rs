@@ -0,0 +1,165 @@
+use super::{AocError, Client, Event, Ledger};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// the outcome of submitting an answer as reported by AoC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, but AoC did not say in which direction
+    Incorrect,
+    /// the part was already completed so the answer was not checked
+    AlreadySolved,
+    /// an answer was submitted too recently and must wait this long to retry
+    RateLimited(Duration),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::RateLimited(_) => write!(f, "rate limited"),
+        }
+    }
+}
+
+/// submits an answer for one part of a puzzle and reports whether it was accepted.
+/// Answers which the day's [`Ledger`] already knows to be wrong are refused
+/// without contacting AoC
+pub fn submit_answer(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: impl Display,
+) -> Result<Verdict, AocError> {
+    Client::from_env()?.submit_answer(year, day, part, answer)
+}
+
+impl Client {
+    /// see [`submit_answer`]
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: impl Display,
+    ) -> Result<Verdict, AocError> {
+        let answer = answer.to_string();
+        let mut ledger = Ledger::load(Ledger::path(self.root(), year, day, self.profile()))?;
+        ledger.check(part, &answer)?;
+        let verdict = self.post_answer(year, day, part, &answer)?;
+        ledger.record(part, &answer, verdict, self.clock.now());
+        ledger.save()?;
+        Ok(verdict)
+    }
+
+    /// submits an answer without consulting or updating the [`Ledger`]
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, AocError> {
+        if part != 1 && part != 2 {
+            return Err(AocError::InvalidPart(part));
+        }
+        if part > Event::new(year)?.day(day)?.parts() {
+            return Err(AocError::Config(format!(
+                "day {day} of {year} only has one part, its second star is awarded for collecting every other star"
+            )));
+        }
+        self.ensure_unlocked(year, day)?;
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        if self.offline {
+            return Err(AocError::Config(format!(
+                "cannot submit to {url} because AOC_OFFLINE is set"
+            )));
+        }
+        let (status, html) = self.send(
+            self.http
+                .post(&url)
+                .header(reqwest::header::COOKIE, self.session_cookie()?)
+                .form(&[("level", part.to_string()), ("answer", answer.to_owned())]),
+        )?;
+        if !(200..300).contains(&status) {
+            return Err(AocError::from_response(
+                self.profile(),
+                year,
+                Some(day),
+                &url,
+                status,
+                &html,
+            ));
+        }
+        // AoC asks to log in instead of giving a verdict
+        if html.contains("please identify yourself") {
+            return Err(self.session_expired());
+        }
+        parse_verdict(&html)
+    }
+}
+
+/// extracts the [`Verdict`] from the page AoC returns after submitting an answer
+pub fn parse_verdict(html: &str) -> Result<Verdict, AocError> {
+    // the message is always rendered inside the only <article> on the page
+    let message = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .ok_or(AocError::UnexpectedResponse(
+            "answer response should contain an <article> element".to_owned(),
+        ))?;
+
+    if message.contains("That's the right answer") {
+        return Ok(Verdict::Correct);
+    }
+    if message.contains("your answer is too high") {
+        return Ok(Verdict::TooHigh);
+    }
+    if message.contains("your answer is too low") {
+        return Ok(Verdict::TooLow);
+    }
+    if message.contains("That's not the right answer") {
+        return Ok(Verdict::Incorrect);
+    }
+    if message.contains("You don't seem to be solving the right level") {
+        return Ok(Verdict::AlreadySolved);
+    }
+    if message.contains("You gave an answer too recently") {
+        // Ex: "You have 1m 5s left to wait."
+        let wait = message
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .ok_or(AocError::UnexpectedResponse(format!(
+                "rate limit message should include the time left to wait: {}",
+                message.trim()
+            )))?;
+        return Ok(Verdict::RateLimited(wait));
+    }
+    Err(AocError::UnexpectedResponse(message.trim().to_owned()))
+}
+
+/// parses durations in the format AoC uses for rate limits (Ex: "1m 5s")
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |total, token| {
+            // the text comes from AoC so the unit may not be a single byte
+            let (index, _) = token.char_indices().last()?;
+            let (number, unit) = token.split_at(index);
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(number.parse::<u64>().ok()? * seconds))
+        })
+}
@@ -0,0 +1,63 @@
+use super::{AocError, Client, unlock_time};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::time::Duration;
+
+/// the source of the current time, which tests replace to control when
+/// puzzles unlock without actually waiting
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// formats the time left until a puzzle unlocks (Ex: 01:02:03)
+fn countdown(remaining: TimeDelta) -> String {
+    // round up so that the countdown never shows 00:00:00 before the unlock
+    let seconds = (remaining.num_milliseconds() + 999) / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+impl Client {
+    /// fails if a puzzle has not unlocked yet, or sleeps with a countdown until
+    /// it does if the client waits for unlocks
+    pub(super) fn ensure_unlocked(&self, year: u16, day: u8) -> Result<(), AocError> {
+        let unlock = unlock_time(year, day)?;
+        let mut waited = false;
+        loop {
+            let remaining = unlock - self.clock.now();
+            if remaining <= TimeDelta::zero() {
+                if waited {
+                    eprintln!();
+                }
+                return Ok(());
+            }
+            if !self.wait_for_unlock {
+                return Err(AocError::PuzzleNotUnlocked { year, day });
+            }
+            eprint!("\rDay {day} of {year} unlocks in {}", countdown(remaining));
+            waited = true;
+            self.clock.sleep(
+                remaining
+                    .min(TimeDelta::seconds(1))
+                    .to_std()
+                    .expect("remaining time should be positive"),
+            );
+        }
+    }
+}
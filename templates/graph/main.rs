@@ -0,0 +1,64 @@
+// {{url}}
+{{#title}}
+// {{title}}
+{{/title}}
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[path = "../../helpers.rs"]
+mod helpers;
+
+#[cfg(test)]
+mod tests;
+
+// used by the tests and when running with --example
+static EXAMPLE_INPUT: &str = {{example}};
+
+use helpers::Input;
+
+/// the nodes which each node is connected to
+type AdjacencyList = HashMap<usize, Vec<usize>>;
+
+fn parse_graph(input: &Input) -> AdjacencyList {
+    let _lines = input.lines().collect::<Vec<_>>();
+    AdjacencyList::new()
+}
+
+/// every node which can be reached from `start`, in the order a breadth first
+/// search visits them
+fn reachable(graph: &AdjacencyList, start: usize) -> Vec<usize> {
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut order = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for &neighbour in graph.get(&node).into_iter().flatten() {
+            if visited.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    order
+}
+
+fn part1(input: &Input) -> {{part1_type}} {
+    let graph = parse_graph(input);
+    let _reachable = reachable(&graph, 0);
+    Default::default()
+}
+{{#part2}}
+
+fn part2(input: &Input) -> {{part2_type}} {
+    let _graph = parse_graph(input);
+    Default::default()
+}
+{{/part2}}
+
+fn main() {
+    let input =
+        helpers::get_input_from_args({{year}}, {{day}}, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
+    println!("Part 1: {}", part1(&input));
+{{#part2}}
+    println!("Part 2: {}", part2(&input));
+{{/part2}}
+}
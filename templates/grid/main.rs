@@ -0,0 +1,43 @@
+// {{url}}
+{{#title}}
+// {{title}}
+{{/title}}
+
+use ndarray::Array2;
+
+#[path = "../../helpers.rs"]
+mod helpers;
+
+#[cfg(test)]
+mod tests;
+
+// used by the tests and when running with --example
+static EXAMPLE_INPUT: &str = {{example}};
+
+use helpers::Input;
+
+/// every character of the input, indexed by row and then column
+fn parse_grid(input: &Input) -> Array2<char> {
+    input.grid().unwrap_or_else(|error| error.exit())
+}
+
+fn part1(input: &Input) -> {{part1_type}} {
+    let _grid = parse_grid(input);
+    Default::default()
+}
+{{#part2}}
+
+fn part2(input: &Input) -> {{part2_type}} {
+    let _grid = parse_grid(input);
+    Default::default()
+}
+{{/part2}}
+
+fn main() {
+    let input =
+        helpers::get_input_from_args({{year}}, {{day}}, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
+    println!("Part 1: {}", part1(&input));
+{{#part2}}
+    println!("Part 2: {}", part2(&input));
+{{/part2}}
+}
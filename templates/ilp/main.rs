@@ -0,0 +1,83 @@
+// {{url}}
+{{#title}}
+// {{title}}
+{{/title}}
+
+// the problems are solved by SCIP through good_lp, see Cargo.toml for what has
+// to be installed for it to build
+
+use good_lp::{Expression, ProblemVariables, Solution, SolverModel, solvers::scip, variable};
+
+#[path = "../../helpers.rs"]
+mod helpers;
+
+#[cfg(test)]
+mod tests;
+
+// used by the tests and when running with --example
+static EXAMPLE_INPUT: &str = {{example}};
+
+use helpers::Input;
+
+/// the coefficients of each constraint (one per variable) and the value it
+/// has to add up to
+fn parse_problem(_line: &str) -> (Vec<Vec<i32>>, Vec<i32>) {
+    (Vec::new(), Vec::new())
+}
+
+/// the smallest sum of non-negative integer variables for which every
+/// constraint adds up to its target
+fn minimum_total(coefficients: &[Vec<i32>], targets: &[i32]) -> u64 {
+    let mut problem_variables = ProblemVariables::new();
+    let variables: Vec<_> = (0..coefficients.first().map_or(0, Vec::len))
+        .map(|_| problem_variables.add(variable().integer().min(0).max(i32::MAX)))
+        .collect();
+    let objective = variables
+        .iter()
+        .fold(Expression::from(0), |expr, &var| expr + var);
+    let constraints = coefficients.iter().zip(targets).map(|(row, &target)| {
+        row.iter()
+            .zip(&variables)
+            .fold(Expression::from(0), |expr, (&coefficient, &var)| {
+                expr + coefficient * var
+            })
+            .eq(target)
+    });
+    let solution = problem_variables
+        .minimise(objective)
+        .using(scip::scip)
+        .with_all(constraints)
+        .solve()
+        .expect("every problem should have a solution");
+    variables
+        .iter()
+        .map(|&var| solution.value(var).round() as u64)
+        .sum()
+}
+
+fn part1(input: &Input) -> {{part1_type}} {
+    let _totals = input
+        .lines()
+        .map(|line| {
+            let (coefficients, targets) = parse_problem(line);
+            minimum_total(&coefficients, &targets)
+        })
+        .collect::<Vec<_>>();
+    Default::default()
+}
+{{#part2}}
+
+fn part2(input: &Input) -> {{part2_type}} {
+    let _lines = input.lines().collect::<Vec<_>>();
+    Default::default()
+}
+{{/part2}}
+
+fn main() {
+    let input =
+        helpers::get_input_from_args({{year}}, {{day}}, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
+    println!("Part 1: {}", part1(&input));
+{{#part2}}
+    println!("Part 2: {}", part2(&input));
+{{/part2}}
+}
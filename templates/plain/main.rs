@@ -0,0 +1,36 @@
+// {{url}}
+{{#title}}
+// {{title}}
+{{/title}}
+
+#[path = "../../helpers.rs"]
+mod helpers;
+
+#[cfg(test)]
+mod tests;
+
+// used by the tests and when running with --example
+static EXAMPLE_INPUT: &str = {{example}};
+
+use helpers::Input;
+
+fn part1(input: &Input) -> {{part1_type}} {
+    let _lines = input.lines().collect::<Vec<_>>();
+    Default::default()
+}
+{{#part2}}
+
+fn part2(input: &Input) -> {{part2_type}} {
+    let _lines = input.lines().collect::<Vec<_>>();
+    Default::default()
+}
+{{/part2}}
+
+fn main() {
+    let input =
+        helpers::get_input_from_args({{year}}, {{day}}, EXAMPLE_INPUT).unwrap_or_else(|error| error.exit());
+    println!("Part 1: {}", part1(&input));
+{{#part2}}
+    println!("Part 2: {}", part2(&input));
+{{/part2}}
+}
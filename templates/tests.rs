@@ -0,0 +1,35 @@
+// {{url}}
+{{#part1_answer}}
+
+use super::*;
+{{/part1_answer}}
+{{^part1_answer}}
+{{#part2_answer}}
+
+use super::*;
+{{/part2_answer}}
+{{/part1_answer}}
+{{#part1_answer}}
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1(&Input::example(EXAMPLE_INPUT)), {{part1_answer}});
+}
+{{/part1_answer}}
+{{^part1_answer}}
+
+// the example answer for part 1 could not be found, add a test once it is known
+{{/part1_answer}}
+{{#part2}}
+{{#part2_answer}}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2(&Input::example(EXAMPLE_INPUT)), {{part2_answer}});
+}
+{{/part2_answer}}
+{{^part2_answer}}
+
+// the example answer for part 2 could not be found, add a test once it is known
+{{/part2_answer}}
+{{/part2}}
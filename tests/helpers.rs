@@ -0,0 +1,164 @@
+// https://adventofcode.com is never contacted by these tests, every request is
+// answered by a stand-in server running on localhost instead
+
+#[path = "../helpers.rs"]
+mod helpers;
+
+#[path = "helpers/account.rs"]
+mod account;
+
+#[path = "helpers/args.rs"]
+mod args;
+
+#[path = "helpers/cache.rs"]
+mod cache;
+
+#[path = "helpers/client.rs"]
+mod client;
+
+#[path = "helpers/dotenv.rs"]
+mod dotenv;
+
+#[path = "helpers/error.rs"]
+mod error;
+
+#[path = "helpers/event.rs"]
+mod event;
+
+#[path = "helpers/http.rs"]
+mod http;
+
+#[path = "helpers/input.rs"]
+mod input;
+
+#[path = "helpers/leaderboard.rs"]
+mod leaderboard;
+
+#[path = "helpers/leaks.rs"]
+mod leaks;
+
+#[path = "helpers/ledger.rs"]
+mod ledger;
+
+#[path = "helpers/prefetch.rs"]
+mod prefetch;
+
+#[path = "helpers/puzzle.rs"]
+mod puzzle;
+
+#[path = "helpers/stats.rs"]
+mod stats;
+
+#[path = "helpers/submit.rs"]
+mod submit;
+
+#[path = "helpers/unlock.rs"]
+mod unlock;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use std::cell::RefCell;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::rc::Rc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// a clock which only moves when something sleeps
+#[derive(Clone)]
+struct FakeClock {
+    now: Rc<RefCell<DateTime<Utc>>>,
+    sleeps: Rc<RefCell<Vec<Duration>>>,
+}
+
+impl FakeClock {
+    fn at(now: DateTime<Utc>) -> Self {
+        FakeClock {
+            now: Rc::new(RefCell::new(now)),
+            sleeps: Rc::default(),
+        }
+    }
+}
+
+impl helpers::Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.borrow()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        *self.now.borrow_mut() += TimeDelta::from_std(duration).unwrap();
+        self.sleeps.borrow_mut().push(duration);
+    }
+}
+
+/// a client which fetches from `url` and caches pages in `cache_dir`, which
+/// is also the root that answers are recorded in
+fn client(url: &str, cache_dir: &Path) -> helpers::Client {
+    let url = url.to_owned();
+    let cache_dir = cache_dir.to_str().unwrap().to_owned();
+    helpers::Client::from_vars(move |key| match key {
+        "AOC_BASE_URL" => Some(url.clone()),
+        "AOC_CACHE_DIR" | "AOC_ROOT" => Some(cache_dir.clone()),
+        "SESSION" => Some("abc123".to_owned()),
+        "AOC_MIN_INTERVAL" => Some("0".to_owned()),
+        _ => None,
+    })
+    .unwrap()
+}
+
+/// starts a server which answers one request per entry in `responses` (in
+/// order) and returns its base url along with a handle that yields the raw
+/// requests it received once all the responses have been sent
+fn serve(responses: &[(u16, &str)]) -> (String, JoinHandle<Vec<String>>) {
+    serve_with_headers(
+        &responses
+            .iter()
+            .map(|(status, body)| (*status, "", *body))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// like [`serve`] but each response also has extra headers (Ex: "Location: /\r\n")
+fn serve_with_headers(responses: &[(u16, &str, &str)]) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("should bind to an ephemeral port");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let responses = responses
+        .iter()
+        .map(|(status, headers, body)| (*status, headers.to_string(), body.to_string()))
+        .collect::<Vec<_>>();
+    let handle = thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|(status, headers, body)| {
+                let (stream, _) = listener.accept().expect("client should connect");
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} STUB\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                request
+            })
+            .collect()
+    });
+    (url, handle)
+}
@@ -0,0 +1,110 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve, serve_with_headers};
+use chrono::{TimeZone, Utc};
+
+static SETTINGS_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2025/about">[About]</a></li><li><a href="/2025/auth/logout">[Log Out]</a></li></ul></nav><div class="user">alice <a href="/2025/support" class="supporter-badge" title="Advent of Code Supporter">AoC++</a> <span class="star-count">24*</span></div></div></header>
+<main><article><p>Settings</p></article></main>
+</body>
+</html>"#;
+
+#[test]
+fn parses_account() {
+    assert_eq!(
+        parse_account(SETTINGS_PAGE).unwrap(),
+        Account {
+            name: "alice".to_owned(),
+            supporter: true,
+            session_expires: None
+        }
+    );
+    let anonymous = parse_account(
+        r#"<header><div class="user">(anonymous user #12345) <span class="star-count">3*</span></div></header>"#,
+    )
+    .unwrap();
+    assert_eq!(anonymous.name, "(anonymous user #12345)");
+    assert!(!anonymous.supporter);
+    assert!(matches!(
+        parse_account("<main></main>"),
+        Err(AocError::UnexpectedResponse(_))
+    ));
+}
+
+#[test]
+fn whoami_uses_the_latest_event() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, SETTINGS_PAGE)]);
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap());
+    let account = client(&url, cache.path())
+        .with_clock(clock)
+        .whoami()
+        .unwrap();
+    assert_eq!(account.name, "alice");
+    assert_eq!(account.session_expires, None);
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2025/settings "));
+    assert!(requests[0].contains("cookie: session=abc123"));
+}
+
+#[test]
+fn session_expiry_from_response() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve_with_headers(&[(
+        200,
+        "Set-Cookie: session=abc123; Domain=.adventofcode.com; Expires=Sat, 19 Dec 2026 05:00:00 GMT; Path=/; Secure; HttpOnly\r\n",
+        SETTINGS_PAGE,
+    )]);
+    let now = Utc.with_ymd_and_hms(2026, 12, 9, 5, 0, 0).unwrap();
+    let account = client(&url, cache.path())
+        .with_clock(FakeClock::at(now))
+        .whoami()
+        .unwrap();
+    server.join().unwrap();
+    assert_eq!(
+        account.session_expires,
+        Some(Utc.with_ymd_and_hms(2026, 12, 19, 5, 0, 0).unwrap())
+    );
+    assert_eq!(account.days_until_expiry(now), Some(10));
+}
+
+#[test]
+fn session_expiry_from_env() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, SETTINGS_PAGE)]);
+    let cache_dir = cache.path().to_str().unwrap().to_owned();
+    let client = Client::from_vars(|key| match key {
+        "AOC_BASE_URL" => Some(url.clone()),
+        "AOC_CACHE_DIR" => Some(cache_dir.clone()),
+        "AOC_MIN_INTERVAL" => Some("0".to_owned()),
+        "AOC_PROFILE" => Some("bob".to_owned()),
+        "SESSION_bob" => Some("def456".to_owned()),
+        "SESSION_bob_EXPIRES" => Some("2026-12-25".to_owned()),
+        _ => None,
+    })
+    .unwrap();
+    let account = client.whoami().unwrap();
+    server.join().unwrap();
+    assert_eq!(
+        account.session_expires,
+        Some(Utc.with_ymd_and_hms(2026, 12, 25, 0, 0, 0).unwrap())
+    );
+
+    let invalid = Client::from_vars(|key| match key {
+        "SESSION_EXPIRES" => Some("soon".to_owned()),
+        _ => None,
+    });
+    assert!(matches!(invalid, Err(AocError::Config(_))));
+}
+
+#[test]
+fn whoami_with_expired_session() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve_with_headers(&[(302, "Location: /2025/auth/login\r\n", "")]);
+    assert!(matches!(
+        client(&url, cache.path()).whoami(),
+        Err(AocError::SessionExpired(_))
+    ));
+    server.join().unwrap();
+}
@@ -0,0 +1,105 @@
+use super::helpers::*;
+use super::{client, serve};
+use std::fs;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> Result<DayArgs, AocError> {
+    parse_day_args(args.iter().map(|arg| arg.to_string()))
+}
+
+fn source(args: &[&str]) -> InputSource {
+    parse(args).unwrap().source
+}
+
+#[test]
+fn input_sources() {
+    assert_eq!(source(&[]), InputSource::Aoc);
+    assert_eq!(
+        source(&["--input", "friend.txt"]),
+        InputSource::File(PathBuf::from("friend.txt"))
+    );
+    assert_eq!(
+        source(&["--input=friend.txt"]),
+        InputSource::File(PathBuf::from("friend.txt"))
+    );
+    assert_eq!(source(&["--input", "-"]), InputSource::Stdin);
+    assert_eq!(source(&["-"]), InputSource::Stdin);
+    assert_eq!(source(&["--example"]), InputSource::Example);
+}
+
+#[test]
+fn wait() {
+    assert!(!parse(&[]).unwrap().wait);
+    assert!(parse(&["--wait"]).unwrap().wait);
+    assert_eq!(
+        parse(&["--example", "--wait"]).unwrap(),
+        DayArgs {
+            source: InputSource::Example,
+            wait: true
+        }
+    );
+}
+
+#[test]
+fn invalid_args() {
+    assert!(matches!(parse(&["--input"]), Err(AocError::Config(_))));
+    assert!(matches!(parse(&["--exmaple"]), Err(AocError::Config(_))));
+    assert!(matches!(
+        parse(&["--example", "--input", "friend.txt"]),
+        Err(AocError::Config(_))
+    ));
+}
+
+/// the AoC input is the only one which needs a client
+fn no_client() -> Result<Client, AocError> {
+    panic!("only the AoC input should configure a client")
+}
+
+#[test]
+fn reads_each_source() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("friend.txt");
+    fs::write(&path, "1\r\n2\r\n").unwrap();
+    let example = r#"
+        3
+        4
+    "#;
+    assert_eq!(
+        read_input(&InputSource::File(path), 2025, 3, example, no_client).unwrap(),
+        "1\n2\n"
+    );
+    assert_eq!(
+        read_input(&InputSource::Example, 2025, 3, example, no_client).unwrap(),
+        "3\n4\n"
+    );
+    assert!(matches!(
+        read_input(
+            &InputSource::File(dir.path().join("missing.txt")),
+            2025,
+            3,
+            example,
+            no_client
+        ),
+        Err(AocError::Io(_))
+    ));
+}
+
+#[test]
+fn reads_aoc_input_with_client() {
+    let (url, server) = serve(&[(200, "5\n6\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let input = read_input(&InputSource::Aoc, 2025, 3, "", || {
+        Ok(client(&url, cache.path()))
+    });
+    assert_eq!(input.unwrap(), "5\n6\n");
+    server.join().unwrap();
+}
+
+#[test]
+fn describes_sources() {
+    assert_eq!(
+        InputSource::File(PathBuf::from("friend.txt")).to_string(),
+        "the input in friend.txt"
+    );
+    assert_eq!(InputSource::Example.to_string(), "the example");
+}
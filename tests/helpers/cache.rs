@@ -0,0 +1,267 @@
+use super::helpers::*;
+use super::{client, serve};
+use std::fs;
+
+#[test]
+fn page_paths() {
+    let input = Page::Input { year: 2025, day: 5 };
+    assert_eq!(input.url_path(), "/2025/day/5/input");
+    assert_eq!(input.cache_path().to_str(), Some("2025/5/input.txt"));
+    let puzzle = Page::Puzzle {
+        year: 2024,
+        day: 25,
+    };
+    assert_eq!(puzzle.url_path(), "/2024/day/25");
+    assert_eq!(puzzle.cache_path().to_str(), Some("2024/25/puzzle.html"));
+    let leaderboard = Page::Leaderboard {
+        year: 2025,
+        id: 1001,
+    };
+    assert_eq!(
+        leaderboard.url_path(),
+        "/2025/leaderboard/private/view/1001.json"
+    );
+    assert_eq!(
+        leaderboard.cache_path().to_str(),
+        Some("2025/leaderboard/1001.json")
+    );
+    assert_eq!(leaderboard.day(), None);
+    let stats = Page::Stats { year: 2024 };
+    assert_eq!(stats.url_path(), "/2024/leaderboard/self");
+    assert_eq!(stats.cache_path().to_str(), Some("2024/self.html"));
+}
+
+#[test]
+fn migrates_legacy_cache() {
+    let cache = tempfile::tempdir().unwrap();
+    let legacy_file = |url: &str| cache.path().join(format!("{}.cache", legacy_hash(url)));
+    fs::write(
+        legacy_file("https://adventofcode.com/2025/day/5/input"),
+        "input",
+    )
+    .unwrap();
+    fs::write(legacy_file("https://adventofcode.com/2016/day/1"), "puzzle").unwrap();
+    fs::write(cache.path().join("1234.cache"), "unknown").unwrap();
+
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert_eq!(client.migrate_legacy_cache().unwrap(), 2);
+    assert_eq!(
+        fs::read_to_string(cache.path().join("2025/5/input.txt")).unwrap(),
+        "input"
+    );
+    assert_eq!(
+        fs::read_to_string(cache.path().join("2016/1/puzzle.html")).unwrap(),
+        "puzzle"
+    );
+    // files which cannot be identified are moved aside to only warn once
+    assert!(!cache.path().join("1234.cache").exists());
+    assert!(cache.path().join("legacy/1234.cache").exists());
+    assert_eq!(client.migrate_legacy_cache().unwrap(), 0);
+    assert!(cache.path().join("legacy/1234.cache").exists());
+}
+
+#[test]
+fn legacy_cache_never_replaces_pages() {
+    let cache = tempfile::tempdir().unwrap();
+    let legacy_file = cache.path().join(format!(
+        "{}.cache",
+        legacy_hash("https://adventofcode.com/2025/day/5/input")
+    ));
+    fs::write(&legacy_file, "old").unwrap();
+    fs::create_dir_all(cache.path().join("2025/5")).unwrap();
+    fs::write(cache.path().join("2025/5/input.txt"), "new").unwrap();
+
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert_eq!(client.migrate_legacy_cache().unwrap(), 0);
+    assert_eq!(
+        fs::read_to_string(cache.path().join("2025/5/input.txt")).unwrap(),
+        "new"
+    );
+    assert!(!legacy_file.exists());
+    assert!(
+        cache
+            .path()
+            .join("legacy")
+            .join(legacy_file.file_name().unwrap())
+            .exists()
+    );
+}
+
+#[test]
+fn migrates_on_cache_miss() {
+    let cache = tempfile::tempdir().unwrap();
+    fs::write(
+        cache.path().join(format!(
+            "{}.cache",
+            legacy_hash("https://adventofcode.com/2025/day/5/input")
+        )),
+        "input",
+    )
+    .unwrap();
+    // nothing is listening on the base url so this must come from the cache
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert_eq!(client.get_input(2025, 5).unwrap(), "input\n");
+}
+
+#[test]
+fn migrates_once_per_client() {
+    let cache = tempfile::tempdir().unwrap();
+    let legacy_file = cache.path().join(format!(
+        "{}.cache",
+        legacy_hash("https://adventofcode.com/2025/day/5/input")
+    ));
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert!(client.get_input(2025, 4).is_err());
+    fs::write(&legacy_file, "input").unwrap();
+    assert!(client.get_input(2025, 5).is_err());
+    assert!(legacy_file.exists());
+}
+
+#[test]
+fn migrates_into_the_default_profile() {
+    let cache = tempfile::tempdir().unwrap();
+    fs::write(
+        cache.path().join(format!(
+            "{}.cache",
+            legacy_hash("https://adventofcode.com/2025/day/5/input")
+        )),
+        "input",
+    )
+    .unwrap();
+    let client = Client::from_vars(|key| match key {
+        "AOC_CACHE_DIR" => Some(cache.path().to_str().unwrap().to_owned()),
+        "AOC_PROFILE" => Some("alice".to_owned()),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(client.migrate_legacy_cache().unwrap(), 1);
+    assert!(cache.path().join("2025/5/input.txt").exists());
+}
+
+#[test]
+fn records_metadata() {
+    let (url, server) = serve(&[(200, "1 2\n3 4\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let client = client(&url, cache.path());
+    client.get_input(2025, 3).unwrap();
+    server.join().unwrap();
+
+    let entries = client.cache_entries(None, None).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].page, Page::Input { year: 2025, day: 3 });
+    let metadata = entries[0].metadata.clone().unwrap();
+    assert_eq!(metadata.url, format!("{url}/2025/day/3/input"));
+    assert_eq!(metadata.status, 200);
+    assert_eq!(metadata.length, 8);
+    assert_eq!(metadata.session, session_fingerprint("abc123"));
+    assert!(client.verify_cache_entry(&entries[0].page).is_empty());
+}
+
+#[test]
+fn session_fingerprint_hides_session() {
+    let fingerprint = session_fingerprint("abc123");
+    assert_eq!(fingerprint.len(), 12);
+    assert!(!fingerprint.contains("abc123"));
+    assert_ne!(fingerprint, session_fingerprint("abc124"));
+}
+
+#[test]
+fn verifies_cache_entries() {
+    let cache = tempfile::tempdir().unwrap();
+    let page = Page::Input { year: 2025, day: 3 };
+    let write = |data: &str, metadata: &str| {
+        fs::create_dir_all(cache.path().join("2025/3")).unwrap();
+        fs::write(cache.path().join("2025/3/input.txt"), data).unwrap();
+        fs::write(cache.path().join("2025/3/input.meta.toml"), metadata).unwrap();
+    };
+    let metadata = |length: usize, status: u16, session: &str| {
+        format!(
+            "url = \"x\"\nfetched = 2025-12-03T05:00:01Z\nstatus = {status}\nlength = {length}\nsession = \"{session}\"\n"
+        )
+    };
+    let client = client("http://127.0.0.1:9", cache.path());
+    let fingerprint = session_fingerprint("abc123");
+
+    write("1 2\n", &metadata(4, 200, &fingerprint));
+    assert_eq!(client.verify_cache_entry(&page), vec![]);
+
+    write("1 2\n", &metadata(8, 200, &fingerprint));
+    assert_eq!(
+        client.verify_cache_entry(&page),
+        vec![CacheProblem::LengthMismatch {
+            expected: 8,
+            actual: 4
+        }]
+    );
+
+    write("1 2\n", &metadata(4, 200, "someone"));
+    assert_eq!(
+        client.verify_cache_entry(&page),
+        vec![CacheProblem::DifferentSession]
+    );
+
+    let error = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+    write(error, &metadata(error.len(), 400, &fingerprint));
+    assert_eq!(
+        client.verify_cache_entry(&page),
+        vec![CacheProblem::ErrorPage, CacheProblem::Status(400)]
+    );
+    // untrusted pages are fetched again (and fail because nothing is listening)
+    assert!(client.get_input(2025, 3).is_err());
+
+    fs::remove_file(cache.path().join("2025/3/input.meta.toml")).unwrap();
+    fs::write(cache.path().join("2025/3/input.txt"), "1 2\n").unwrap();
+    assert_eq!(
+        client.verify_cache_entry(&page),
+        vec![CacheProblem::MissingMetadata]
+    );
+    // pages cached before metadata was recorded are still used
+    assert_eq!(client.get_input(2025, 3).unwrap(), "1 2\n");
+}
+
+#[test]
+fn lists_and_purges_by_year_and_day() {
+    let cache = tempfile::tempdir().unwrap();
+    for path in [
+        "2024/1/input.txt",
+        "2025/1/input.txt",
+        "2025/1/puzzle.html",
+        "2025/2/input.txt",
+    ] {
+        let path = cache.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "data").unwrap();
+    }
+    fs::write(cache.path().join("2025/1/input.meta.toml"), "").unwrap();
+    fs::write(cache.path().join("2025/notes.txt"), "").unwrap();
+
+    let client = client("http://127.0.0.1:9", cache.path());
+    let pages = |year, day| {
+        client
+            .cache_entries(year, day)
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.page)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(pages(None, None).len(), 4);
+    assert_eq!(
+        pages(Some(2025), Some(1)),
+        vec![
+            Page::Input { year: 2025, day: 1 },
+            Page::Puzzle { year: 2025, day: 1 }
+        ]
+    );
+    assert_eq!(
+        pages(None, Some(2)),
+        vec![Page::Input { year: 2025, day: 2 }]
+    );
+
+    assert_eq!(client.purge_cache(Some(2025), Some(1)).unwrap(), 2);
+    assert!(!cache.path().join("2025/1/input.meta.toml").exists());
+    assert_eq!(pages(None, None).len(), 2);
+    assert_eq!(client.purge_cache(Some(2025), None).unwrap(), 1);
+    assert_eq!(pages(None, None), vec![Page::Input { year: 2024, day: 1 }]);
+    // unrelated files are left alone
+    assert!(cache.path().join("2025/notes.txt").exists());
+}
@@ -0,0 +1,215 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve};
+use chrono::{TimeDelta, TimeZone, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+fn from_map(vars: &[(&str, &str)]) -> Client {
+    let vars = vars
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>();
+    Client::from_vars(|key| vars.get(key).cloned()).unwrap()
+}
+
+#[test]
+fn defaults() {
+    let client = from_map(&[]);
+    assert_eq!(client.base_url(), "https://adventofcode.com");
+    assert!(!client.is_offline());
+}
+
+#[test]
+fn configured_from_vars() {
+    let client = from_map(&[
+        ("AOC_BASE_URL", "http://localhost:8080/"),
+        ("AOC_OFFLINE", "1"),
+    ]);
+    assert_eq!(client.base_url(), "http://localhost:8080");
+    assert!(client.is_offline());
+    assert!(!from_map(&[("AOC_OFFLINE", "0")]).is_offline());
+}
+
+#[test]
+fn profiles() {
+    let client = from_map(&[("AOC_CACHE_DIR", "aoc"), ("SESSION", "abc123")]);
+    assert_eq!(client.profile(), None);
+    assert_eq!(client.cache_dir(), client.root().join("aoc"));
+
+    let client = from_map(&[("AOC_CACHE_DIR", "aoc"), ("AOC_PROFILE", "alice")]);
+    assert_eq!(client.profile(), Some("alice"));
+    assert_eq!(client.cache_dir(), client.root().join("aoc/profiles/alice"));
+    // the default session is never used for a profile
+    let client = from_map(&[("AOC_PROFILE", "alice"), ("SESSION", "abc123")]);
+    let error = client.get_input(2025, 3).unwrap_err();
+    assert!(error.to_string().starts_with("SESSION_alice key missing"));
+
+    assert_eq!(from_map(&[("AOC_PROFILE", " ")]).profile(), None);
+    let error = Client::from_vars(|key| (key == "AOC_PROFILE").then(|| "../bob".to_owned()));
+    assert!(error.is_err());
+}
+
+#[test]
+fn cache_is_relative_to_the_root() {
+    let repo = tempfile::tempdir().unwrap();
+    fs::write(repo.path().join("Cargo.toml"), "").unwrap();
+    let directory = repo.path().join("2025/5");
+    fs::create_dir_all(&directory).unwrap();
+
+    let client = Client::from_vars_in(&directory, |_| None).unwrap();
+    assert_eq!(client.root(), repo.path());
+    assert_eq!(client.cache_root(), repo.path().join("cache"));
+    let client = Client::from_vars_in(&directory, |key| {
+        (key == "AOC_CACHE_DIR").then(|| "aoc".to_owned())
+    })
+    .unwrap();
+    assert_eq!(client.cache_root(), repo.path().join("aoc"));
+}
+
+#[test]
+fn profiles_fetch_their_own_input() {
+    let (url, server) = serve(&[(200, "alice\n"), (200, "bob\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let cache_dir = cache.path().to_str().unwrap();
+    let vars = [
+        ("AOC_BASE_URL", url.as_str()),
+        ("AOC_CACHE_DIR", cache_dir),
+        ("SESSION_alice", "alice-session"),
+        ("SESSION_bob", "bob-session"),
+        ("AOC_MIN_INTERVAL", "0"),
+    ];
+    let alice = from_map(&[&vars[..], &[("AOC_PROFILE", "alice")]].concat());
+    let bob = from_map(&[&vars[..], &[("AOC_PROFILE", "bob")]].concat());
+    assert_eq!(alice.get_input(2025, 3).unwrap(), "alice\n");
+    assert_eq!(bob.get_input(2025, 3).unwrap(), "bob\n");
+    // both are cached separately now
+    assert_eq!(alice.get_input(2025, 3).unwrap(), "alice\n");
+    assert_eq!(bob.get_input(2025, 3).unwrap(), "bob\n");
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].contains("cookie: session=alice-session"));
+    assert!(requests[1].contains("cookie: session=bob-session"));
+    assert_eq!(
+        fs::read_to_string(cache.path().join("profiles/bob/2025/3/input.txt")).unwrap(),
+        "bob\n"
+    );
+}
+
+#[test]
+fn fetches_then_caches_input() {
+    let (url, server) = serve(&[(200, "1 2\n3 4\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let client = client(&url, cache.path());
+    assert_eq!(client.get_input(2025, 3).unwrap(), "1 2\n3 4\n");
+    // the server only answers once so this must come from the cache
+    assert_eq!(client.get_input(2025, 3).unwrap(), "1 2\n3 4\n");
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1"));
+    assert!(requests[0].contains("cookie: session=abc123"));
+    assert_eq!(
+        fs::read_to_string(cache.path().join("2025/3/input.txt")).unwrap(),
+        "1 2\n3 4\n"
+    );
+}
+
+#[test]
+fn http_errors_are_not_cached() {
+    let (url, server) = serve(&[(400, "oops")]);
+    let cache = tempfile::tempdir().unwrap();
+    assert!(client(&url, cache.path()).get_input(2025, 3).is_err());
+    server.join().unwrap();
+    assert_eq!(fs::read_dir(cache.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn offline_cache_miss_fails_fast() {
+    let cache = tempfile::tempdir().unwrap();
+    let client = from_map(&[
+        // nothing is listening here so a request would fail differently
+        ("AOC_BASE_URL", "http://127.0.0.1:9"),
+        ("AOC_CACHE_DIR", cache.path().to_str().unwrap()),
+        ("AOC_OFFLINE", "1"),
+        ("SESSION", "abc123"),
+    ]);
+    let error = client.get_input(2025, 3).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "http://127.0.0.1:9/2025/day/3/input is not cached and AOC_OFFLINE is set"
+    );
+}
+
+#[test]
+fn offline_cache_hit() {
+    let (url, server) = serve(&[(200, "1 2\n3 4\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    client(&url, cache.path()).get_input(2025, 3).unwrap();
+    server.join().unwrap();
+
+    let offline = from_map(&[
+        ("AOC_BASE_URL", &url),
+        ("AOC_CACHE_DIR", cache.path().to_str().unwrap()),
+        ("AOC_OFFLINE", "1"),
+    ]);
+    assert_eq!(offline.get_input(2025, 3).unwrap(), "1 2\n3 4\n");
+}
+
+/// records that part 1 of a day was solved a minute from now, so after the
+/// puzzle page was cached
+fn solve_part1(root: &Path, year: u16, day: u8) {
+    let mut ledger = Ledger::load(Ledger::path(root, year, day, None)).unwrap();
+    ledger.record(1, "42", Verdict::Correct, Utc::now());
+    ledger.save().unwrap();
+}
+
+#[test]
+fn puzzle_page_refreshes_once_part1_is_solved() {
+    let part1 = include_str!("../fixtures/puzzle_part1.html");
+    let both = include_str!("../fixtures/puzzle.html");
+    let correct = "<main><article><p>That's the right answer!</p></article></main>";
+    let (url, server) = serve(&[(200, part1), (200, correct), (200, both)]);
+    let cache = tempfile::tempdir().unwrap();
+    // both the page and the answer are timestamped by the client's clock
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+    let client = client(&url, cache.path()).with_clock(clock.clone());
+    assert_eq!(count_parts(&client.get_puzzle_page(2025, 3).unwrap()), 1);
+    // part 2 cannot have been unlocked yet
+    assert_eq!(count_parts(&client.get_puzzle_page(2025, 3).unwrap()), 1);
+    *clock.now.borrow_mut() += TimeDelta::minutes(1);
+    assert_eq!(
+        client.submit_answer(2025, 3, 1, 42).unwrap(),
+        Verdict::Correct
+    );
+    assert_eq!(count_parts(&client.get_puzzle_page(2025, 3).unwrap()), 2);
+    // once both parts are cached there is no need to fetch the page again
+    assert_eq!(count_parts(&client.get_puzzle_page(2025, 3).unwrap()), 2);
+    assert_eq!(server.join().unwrap().len(), 3);
+}
+
+#[test]
+fn last_puzzle_page_is_never_refreshed() {
+    let part1 = include_str!("../fixtures/puzzle_part1.html");
+    let (url, server) = serve(&[(200, part1)]);
+    let cache = tempfile::tempdir().unwrap();
+    let client = client(&url, cache.path());
+    client.get_puzzle_page(2025, 12).unwrap();
+    solve_part1(cache.path(), 2025, 12);
+    assert_eq!(count_parts(&client.get_puzzle_page(2025, 12).unwrap()), 1);
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn puzzle_page_refreshed_on_request() {
+    let part1 = include_str!("../fixtures/puzzle_part1.html");
+    let both = include_str!("../fixtures/puzzle.html");
+    let (url, server) = serve(&[(200, part1), (200, both)]);
+    let cache = tempfile::tempdir().unwrap();
+    let client = client(&url, cache.path());
+    client.get_puzzle_page(2025, 3).unwrap();
+    assert_eq!(
+        count_parts(&client.refresh_puzzle_page(2025, 3).unwrap()),
+        2
+    );
+    assert_eq!(server.join().unwrap().len(), 2);
+}
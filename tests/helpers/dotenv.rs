@@ -0,0 +1,97 @@
+use super::helpers::*;
+use std::collections::HashMap;
+use std::fs;
+
+fn parse(content: &str) -> HashMap<String, String> {
+    parse_dotenv(content).unwrap()
+}
+
+fn parse_error(content: &str) -> DotenvError {
+    parse_dotenv(content).unwrap_err()
+}
+
+#[test]
+fn simple_values() {
+    let vars = parse("SESSION=abc123\nAOC_OFFLINE=1\n");
+    assert_eq!(vars["SESSION"], "abc123");
+    assert_eq!(vars["AOC_OFFLINE"], "1");
+    assert_eq!(vars.len(), 2);
+}
+
+#[test]
+fn values_containing_equals() {
+    assert_eq!(parse("TOKEN=a=b==")["TOKEN"], "a=b==");
+}
+
+#[test]
+fn comments_whitespace_and_export() {
+    let vars = parse(
+        "# the session cookie\n\n  export SESSION = abc123  # from the browser\r\nHASH=abc#123\r\nEMPTY=\r\n",
+    );
+    assert_eq!(vars["SESSION"], "abc123");
+    assert_eq!(vars["HASH"], "abc#123");
+    assert_eq!(vars["EMPTY"], "");
+    assert_eq!(vars.len(), 3);
+    // a key which happens to start with "export" is not an export
+    assert_eq!(parse("exported=1")["exported"], "1");
+}
+
+#[test]
+fn quoted_values() {
+    let vars = parse(concat!(
+        "SINGLE='a \\n # b'\n",
+        "DOUBLE=\"a\\n\\\"b\\\" \\\\ # c\"  # comment\n",
+        "MULTI=\"line 1\nline 2\"\n",
+        "AFTER=1\n",
+    ));
+    assert_eq!(vars["SINGLE"], "a \\n # b");
+    assert_eq!(vars["DOUBLE"], "a\n\"b\" \\ # c");
+    assert_eq!(vars["MULTI"], "line 1\nline 2");
+    assert_eq!(vars["AFTER"], "1");
+}
+
+#[test]
+fn whitespace_inside_quotes_is_kept() {
+    let vars = parse("KEY=\"  a  \nb  \"  \nSINGLE='  c  \n  d'\nPLAIN=  e  \n");
+    assert_eq!(vars["KEY"], "  a  \nb  ");
+    assert_eq!(vars["SINGLE"], "  c  \n  d");
+    assert_eq!(vars["PLAIN"], "e");
+}
+
+#[test]
+fn later_values_win() {
+    assert_eq!(parse("A=1\nA=2")["A"], "2");
+}
+
+#[test]
+fn errors_include_line_numbers() {
+    assert_eq!(parse_error("A=1\n\nnot an assignment\n").line, 3);
+    assert_eq!(parse_error("A=1\nB C=2").line, 2);
+    assert_eq!(parse_error("1A=2").line, 1);
+    let error = parse_error("A=1\nB=\"never closed\nC=3\n");
+    assert_eq!(error.line, 2);
+    assert_eq!(error.to_string(), "line 2: \" is never closed");
+    assert_eq!(parse_error("A='quoted' trailing").line, 1);
+}
+
+#[test]
+fn finds_dotenv_in_parent_directories() {
+    let root = tempfile::tempdir().unwrap();
+    let nested = root.path().join("2025/5");
+    fs::create_dir_all(&nested).unwrap();
+    assert_eq!(find_dotenv(&nested), None);
+
+    fs::write(root.path().join(".env"), "SESSION=abc123\n").unwrap();
+    assert_eq!(find_dotenv(&nested), Some(root.path().join(".env")));
+    fs::write(nested.join(".env"), "SESSION=def456\n").unwrap();
+    assert_eq!(find_dotenv(&nested), Some(nested.join(".env")));
+}
+
+#[test]
+fn read_errors_include_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".env");
+    fs::write(&path, "SESSION=abc123\nbroken\n").unwrap();
+    let error = read_dotenv(&path).unwrap_err().to_string();
+    assert!(error.starts_with(&format!("{}: line 2: ", path.display())));
+}
@@ -0,0 +1,121 @@
+use super::helpers::*;
+use super::{client, serve};
+use std::fs;
+
+fn fetch_error(status: u16, body: &str) -> AocError {
+    let (url, server) = serve(&[(status, body)]);
+    let cache = tempfile::tempdir().unwrap();
+    let error = client(&url, cache.path()).get_input(2025, 3).unwrap_err();
+    server.join().unwrap();
+    error
+}
+
+#[test]
+fn puzzle_not_unlocked() {
+    let error = fetch_error(
+        400,
+        "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+    );
+    assert!(matches!(
+        error,
+        AocError::PuzzleNotUnlocked { year: 2025, day: 3 }
+    ));
+
+    let (url, server) = serve(&[(404, "404 Not Found")]);
+    let cache = tempfile::tempdir().unwrap();
+    let error = client(&url, cache.path())
+        .get_puzzle_page(2025, 3)
+        .unwrap_err();
+    server.join().unwrap();
+    assert!(matches!(
+        error,
+        AocError::PuzzleNotUnlocked { year: 2025, day: 3 }
+    ));
+}
+
+#[test]
+fn session_expired() {
+    let error = fetch_error(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    );
+    assert!(matches!(&error, AocError::SessionExpired(key) if key == "SESSION"));
+    assert!(error.to_string().contains("update the SESSION key"));
+}
+
+#[test]
+fn session_expired_for_profile() {
+    let (url, server) = serve(&[(400, "Puzzle inputs differ by user.\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let error = Client::from_vars(|key| match key {
+        "AOC_BASE_URL" => Some(url.clone()),
+        "AOC_CACHE_DIR" => Some(cache.path().to_str().unwrap().to_owned()),
+        "AOC_PROFILE" => Some("alice".to_owned()),
+        "SESSION_alice" => Some("abc123".to_owned()),
+        "AOC_MIN_INTERVAL" => Some("0".to_owned()),
+        _ => None,
+    })
+    .unwrap()
+    .get_input(2025, 3)
+    .unwrap_err();
+    server.join().unwrap();
+    assert!(error.to_string().contains("update the SESSION_alice key"));
+}
+
+#[test]
+fn no_such_day_before_the_first_event() {
+    let error = AocError::NoSuchDay { year: 2014, day: 1 };
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "there was no event in 2014, the first was in {}",
+            Event::FIRST_YEAR
+        )
+    );
+}
+
+#[test]
+fn other_statuses() {
+    assert!(matches!(fetch_error(429, ""), AocError::RateLimited));
+    match fetch_error(404, "oops") {
+        AocError::Http { url, status } => {
+            assert_eq!(status, 404);
+            assert!(url.ends_with("/2025/day/3/input"));
+        }
+        error => panic!("expected an HTTP error but was {error:?}"),
+    }
+}
+
+#[test]
+fn missing_session() {
+    let cache = tempfile::tempdir().unwrap();
+    let error = Client::from_vars(|key| {
+        (key == "AOC_CACHE_DIR").then(|| cache.path().to_str().unwrap().to_owned())
+    })
+    .unwrap()
+    .get_input(2025, 3)
+    .unwrap_err();
+    assert!(matches!(&error, AocError::MissingSession(key) if key == "SESSION"));
+    assert!(error.to_string().contains(".env"));
+}
+
+#[test]
+fn corrupt_cache_while_offline() {
+    let cache = tempfile::tempdir().unwrap();
+    fs::create_dir_all(cache.path().join("2025/3")).unwrap();
+    fs::write(cache.path().join("2025/3/input.txt"), "").unwrap();
+    let cache_dir = cache.path().to_str().unwrap().to_owned();
+    let client = Client::from_vars(|key| match key {
+        "AOC_OFFLINE" => Some("1".to_owned()),
+        "AOC_CACHE_DIR" => Some(cache_dir.clone()),
+        _ => None,
+    })
+    .unwrap();
+    match client.get_input(2025, 3).unwrap_err() {
+        AocError::CacheCorrupt { path, reason } => {
+            assert_eq!(path, cache.path().join("2025/3/input.txt"));
+            assert!(reason.contains("empty"));
+        }
+        error => panic!("expected a corrupt cache but was {error:?}"),
+    }
+}
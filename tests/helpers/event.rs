@@ -0,0 +1,86 @@
+use super::helpers::*;
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn valid_years() {
+    assert!(matches!(Event::new(2014), Err(AocError::NoSuchEvent(2014))));
+    assert_eq!(Event::new(2015).unwrap().year(), 2015);
+    assert_eq!(Event::parse(" 2024 ").unwrap(), Event::new(2024).unwrap());
+    assert!(matches!(Event::parse("24th"), Err(AocError::Config(_))));
+    assert_eq!(
+        AocError::NoSuchEvent(2014).to_string(),
+        "there was no event in 2014, the first was in 2015"
+    );
+}
+
+#[test]
+fn days_per_event() {
+    assert_eq!(Event::new(2015).unwrap().days(), 25);
+    assert_eq!(Event::new(2024).unwrap().days(), 25);
+    assert_eq!(Event::new(2025).unwrap().days(), 12);
+    assert_eq!(Event::new(2024).unwrap().all_days().count(), 25);
+    assert_eq!(
+        Event::new(2025)
+            .unwrap()
+            .all_days()
+            .map(|day| day.number())
+            .collect::<Vec<_>>(),
+        (1..=12).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn valid_days() {
+    let event = Event::new(2025).unwrap();
+    assert_eq!(event.day(12).unwrap().to_string(), "day 12 of 2025");
+    assert!(matches!(
+        event.day(13),
+        Err(AocError::NoSuchDay {
+            year: 2025,
+            day: 13
+        })
+    ));
+    assert!(event.day(0).is_err());
+    assert_eq!(Day::parse("2024", "25").unwrap().number(), 25);
+    assert!(matches!(Day::parse("2024", "x"), Err(AocError::Config(_))));
+    assert!(matches!(
+        Day::parse("2014", "1"),
+        Err(AocError::NoSuchEvent(2014))
+    ));
+}
+
+#[test]
+fn last_day_has_one_part() {
+    assert_eq!(Day::parse("2024", "24").unwrap().parts(), 2);
+    assert_eq!(Day::parse("2024", "25").unwrap().parts(), 1);
+    assert_eq!(Day::parse("2025", "11").unwrap().parts(), 2);
+    assert_eq!(Day::parse("2025", "12").unwrap().parts(), 1);
+}
+
+#[test]
+fn unlocked_days() {
+    let event = Event::new(2025).unwrap();
+    let now = Utc.with_ymd_and_hms(2025, 12, 3, 4, 59, 59).unwrap();
+    assert_eq!(
+        event
+            .unlocked_days(now)
+            .map(|day| day.number())
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!(
+        event
+            .day(3)
+            .unwrap()
+            .is_unlocked(now + chrono::TimeDelta::seconds(1))
+    );
+}
+
+#[test]
+fn latest_event() {
+    let before = Utc.with_ymd_and_hms(2025, 12, 1, 4, 59, 59).unwrap();
+    assert_eq!(Event::latest(before).year(), 2024);
+    let after = Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap();
+    assert_eq!(Event::latest(after).year(), 2025);
+    assert_eq!(Event::all(after).count(), 11);
+}
@@ -0,0 +1,139 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve, serve_with_headers};
+use chrono::{TimeDelta, TimeZone, Utc};
+use std::path::Path;
+use std::time::Duration;
+
+/// a client with a fake clock, which is set well after every 2025 puzzle unlocked
+fn client_at(url: &str, cache_dir: &Path, min_interval: &str, clock: &FakeClock) -> Client {
+    let url = url.to_owned();
+    let cache_dir = cache_dir.to_str().unwrap().to_owned();
+    let min_interval = min_interval.to_owned();
+    Client::from_vars(move |key| match key {
+        "AOC_BASE_URL" => Some(url.clone()),
+        "AOC_CACHE_DIR" => Some(cache_dir.clone()),
+        "AOC_MIN_INTERVAL" => Some(min_interval.clone()),
+        "AOC_USER_AGENT" => Some("tests (someone@example.com)".to_owned()),
+        "SESSION" => Some("abc123".to_owned()),
+        _ => None,
+    })
+    .unwrap()
+    .with_clock(clock.clone())
+}
+
+fn clock() -> FakeClock {
+    FakeClock::at(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap())
+}
+
+#[test]
+fn identifies_itself() {
+    let (url, server) = serve(&[(200, "1 2\n"), (200, "3 4\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    client(&url, cache.path()).get_input(2025, 3).unwrap();
+    client_at(&url, cache.path(), "0", &clock())
+        .get_input(2025, 4)
+        .unwrap();
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].contains("user-agent: github.com/jarpoole/advent_of_code\r\n"));
+    assert!(requests[1].contains("user-agent: tests (someone@example.com)\r\n"));
+}
+
+#[test]
+fn throttles_requests_across_clients() {
+    let (url, server) = serve(&[(200, "1\n"), (200, "2\n"), (200, "3\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let clock = clock();
+    let first = client_at(&url, cache.path(), "60", &clock);
+    first.get_input(2025, 1).unwrap();
+    assert!(clock.sleeps.borrow().is_empty());
+    assert!(cache.path().join("last-request").exists());
+
+    // the time of the last request is shared through the cache directory
+    *clock.now.borrow_mut() += TimeDelta::seconds(10);
+    let second = client_at(&url, cache.path(), "60", &clock);
+    second.get_input(2025, 2).unwrap();
+    assert_eq!(*clock.sleeps.borrow(), vec![Duration::from_secs(50)]);
+
+    *clock.now.borrow_mut() += TimeDelta::seconds(90);
+    first.get_input(2025, 3).unwrap();
+    assert_eq!(clock.sleeps.borrow().len(), 1);
+    server.join().unwrap();
+}
+
+#[test]
+fn invalid_min_interval() {
+    let result = Client::from_vars(|key| (key == "AOC_MIN_INTERVAL").then(|| "soon".to_owned()));
+    assert!(matches!(result, Err(AocError::Config(_))));
+    // too long to add to a date
+    let result = Client::from_vars(|key| (key == "AOC_MIN_INTERVAL").then(|| "1e18".to_owned()));
+    assert!(matches!(result, Err(AocError::Config(_))));
+}
+
+#[test]
+fn retries_server_errors_with_backoff() {
+    let (url, server) = serve(&[(503, ""), (502, ""), (500, ""), (200, "1 2\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let clock = clock();
+    let client = client_at(&url, cache.path(), "0", &clock);
+    assert_eq!(client.get_input(2025, 3).unwrap(), "1 2\n");
+    server.join().unwrap();
+    assert_eq!(
+        *clock.sleeps.borrow(),
+        vec![
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+            Duration::from_secs(4)
+        ]
+    );
+}
+
+#[test]
+fn gives_up_after_retrying() {
+    let (url, server) = serve(&[(500, ""), (500, ""), (500, ""), (500, "")]);
+    let cache = tempfile::tempdir().unwrap();
+    let client = client_at(&url, cache.path(), "0", &clock());
+    assert!(matches!(
+        client.get_input(2025, 3),
+        Err(AocError::Http { status: 500, .. })
+    ));
+    assert_eq!(server.join().unwrap().len(), 4);
+}
+
+#[test]
+fn never_retries_answers() {
+    let (url, server) = serve(&[(500, "")]);
+    let cache = tempfile::tempdir().unwrap();
+    let clock = clock();
+    let client = client_at(&url, cache.path(), "0", &clock);
+    assert!(client.post_answer(2025, 3, 1, "14").is_err());
+    assert_eq!(server.join().unwrap().len(), 1);
+    assert!(clock.sleeps.borrow().is_empty());
+}
+
+#[test]
+fn detects_login_redirect() {
+    let (url, server) = serve_with_headers(&[(302, "Location: /2025/auth/login\r\n", "")]);
+    let cache = tempfile::tempdir().unwrap();
+    assert!(matches!(
+        client(&url, cache.path()).get_input(2025, 3),
+        Err(AocError::SessionExpired(_))
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn detects_logged_out_page() {
+    let (url, server) = serve(&[(
+        200,
+        r#"<html><header><a href="/2025/auth/login">[Log In]</a></header><main><article class="day-desc"></article></main></html>"#,
+    )]);
+    let cache = tempfile::tempdir().unwrap();
+    assert!(matches!(
+        client(&url, cache.path()).get_puzzle_page(2025, 3),
+        Err(AocError::SessionExpired(_))
+    ));
+    server.join().unwrap();
+    // the logged out page must not be cached
+    assert!(!cache.path().join("2025/3/puzzle.html").exists());
+}
@@ -0,0 +1,98 @@
+use super::helpers::*;
+use ndarray::array;
+
+#[test]
+fn normalizes_line_endings() {
+    assert_eq!(Input::new("a\r\nb\r\n\r\n").raw(), "a\nb\n");
+    assert_eq!(Input::new("a\nb").raw(), "a\nb\n");
+    assert_eq!(Input::new("\n\n").raw(), "");
+    // leading whitespace can be significant so it is kept
+    assert_eq!(Input::new("  a\n b \n").raw(), "  a\n b \n");
+}
+
+#[test]
+fn dedents_examples() {
+    let example = r#"
+        3-5
+          10-14
+
+        1
+    "#;
+    assert_eq!(Input::example(example).raw(), "3-5\n  10-14\n\n1\n");
+    // examples which do not start with a newline are taken as they are
+    assert_eq!(Input::example("  a\n  b").raw(), "  a\n  b\n");
+    assert_eq!(Input::example("\r\n  a\r\n  b\r\n").raw(), "a\nb\n");
+}
+
+#[test]
+fn lines_and_paragraphs() {
+    let input = Input::new("3-5\n10-14\n\n1\n5\r\n");
+    assert_eq!(
+        input.lines().collect::<Vec<_>>(),
+        ["3-5", "10-14", "", "1", "5"]
+    );
+    let paragraphs = input.paragraphs().collect::<Vec<_>>();
+    assert_eq!(paragraphs, [Input::new("3-5\n10-14"), Input::new("1\n5")]);
+    assert_eq!(paragraphs[1].parse_lines::<u64>().unwrap(), [1, 5]);
+}
+
+#[test]
+fn parse_lines_reports_the_invalid_line() {
+    let input = Input::new("1\n2\nx\n");
+    match input.parse_lines::<u32>() {
+        Err(AocError::InvalidInput { line, reason }) => {
+            assert_eq!(line, 3);
+            assert!(reason.starts_with("'x' "));
+        }
+        result => panic!("expected invalid input but was {result:?}"),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Cell {
+    Empty,
+    Roll,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(char: char) -> Result<Self, ()> {
+        match char {
+            '.' => Ok(Cell::Empty),
+            '@' => Ok(Cell::Roll),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn grids() {
+    let input = Input::new("..@\n@@.\n");
+    assert_eq!(
+        input.grid::<char>().unwrap(),
+        array![['.', '.', '@'], ['@', '@', '.']]
+    );
+    assert_eq!(
+        input.grid::<Cell>().unwrap(),
+        array![
+            [Cell::Empty, Cell::Empty, Cell::Roll],
+            [Cell::Roll, Cell::Roll, Cell::Empty]
+        ]
+    );
+    assert!(matches!(
+        Input::new("..\n.#\n").grid::<Cell>(),
+        Err(AocError::InvalidInput { line: 2, .. })
+    ));
+    assert!(matches!(
+        Input::new("...\n..\n").grid::<char>(),
+        Err(AocError::InvalidInput { line: 2, .. })
+    ));
+}
+
+#[test]
+fn dereferences_to_the_text() {
+    let input = Input::new("1 2\n");
+    assert_eq!(input.trim(), "1 2");
+    assert_eq!(input.len(), 4);
+}
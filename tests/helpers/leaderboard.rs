@@ -0,0 +1,95 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve};
+use chrono::{TimeDelta, TimeZone, Utc};
+
+static LEADERBOARD: &str = include_str!("../fixtures/leaderboard.json");
+
+#[test]
+fn parses_leaderboard() {
+    let leaderboard = parse_leaderboard(LEADERBOARD).unwrap();
+    assert_eq!(leaderboard.owner_id, 1001);
+    assert_eq!(leaderboard.event, "2025");
+    assert_eq!(leaderboard.members.len(), 3);
+    let alice = &leaderboard.members["1001"];
+    assert_eq!(alice.name.as_deref(), Some("alice"));
+    assert_eq!((alice.stars, alice.local_score), (4, 11));
+    assert_eq!(
+        alice.star_time(1, 2),
+        Some(Utc.with_ymd_and_hms(2025, 12, 1, 5, 6, 40).unwrap())
+    );
+    assert_eq!(alice.star_time(3, 1), None);
+    let anonymous = &leaderboard.members["1002"];
+    assert_eq!(anonymous.display_name(), "(anonymous user #1002)");
+    assert_eq!(anonymous.star_time(2, 2), None);
+    assert!(leaderboard.members["1003"].completion_day_level.is_empty());
+    assert_eq!(
+        leaderboard
+            .ranking()
+            .iter()
+            .map(|member| member.id)
+            .collect::<Vec<_>>(),
+        vec![1001, 1002, 1003]
+    );
+}
+
+#[test]
+fn rejects_invalid_leaderboard() {
+    assert!(matches!(
+        parse_leaderboard("<html>"),
+        Err(AocError::UnexpectedResponse(_))
+    ));
+}
+
+#[test]
+fn renders_leaderboard() {
+    let leaderboard = parse_leaderboard(LEADERBOARD).unwrap();
+    assert_eq!(
+        render_leaderboard(Event::new(2025).unwrap(), &leaderboard),
+        "Private leaderboard of alice for 2025
+
+Rank  Name                    Score  Stars   1  2  3  4  5  6  7  8  9 10 11 12
+   1  alice                      11      4   ★  ★  ·  ·  ·  ·  ·  ·  ·  ·  ·  ·
+   2  (anonymous user #1002)      7      3   ★  ☆  ·  ·  ·  ·  ·  ·  ·  ·  ·  ·
+   3  carol                       0      0   ·  ·  ·  ·  ·  ·  ·  ·  ·  ·  ·  ·
+
+Completion times (part 1 since unlock, part 2 since part 1)
+
+Name                    Day      Part 1      Part 2
+alice                     1    00:01:40   +00:05:00
+alice                     2    00:06:40   +00:08:20
+(anonymous user #1002)    1    01:00:00   +01:00:00
+(anonymous user #1002)    2    01:00:00
+"
+    );
+}
+
+#[test]
+fn formats_durations() {
+    assert_eq!(format_duration(TimeDelta::seconds(59)), "00:00:59");
+    assert_eq!(format_duration(TimeDelta::seconds(93_789)), "26:03:09");
+}
+
+#[test]
+fn caches_leaderboard_for_15_minutes() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, LEADERBOARD), (200, LEADERBOARD)]);
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 2, 12, 0, 0).unwrap());
+    let client = client(&url, cache.path()).with_clock(clock.clone());
+    client.get_leaderboard(2025, 1001).unwrap();
+    *clock.now.borrow_mut() += TimeDelta::minutes(14);
+    client.get_leaderboard(2025, 1001).unwrap();
+    *clock.now.borrow_mut() += TimeDelta::minutes(1);
+    client.get_leaderboard(2025, 1001).unwrap();
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /2025/leaderboard/private/view/1001.json "));
+    assert!(requests[0].contains("cookie: session=abc123"));
+    assert_eq!(
+        client.cache_entries(Some(2025), None).unwrap()[0].page,
+        Page::Leaderboard {
+            year: 2025,
+            id: 1001
+        }
+    );
+}
@@ -0,0 +1,98 @@
+use super::helpers::*;
+use super::{client, serve};
+use git2::Repository;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// a repository with a cached input in `cache/`
+fn repo_with_cache() -> (tempfile::TempDir, Repository, Client) {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    let (url, server) = serve(&[(200, "1\n2\n3\n")]);
+    let client = client(&url, &dir.path().join("cache"));
+    client.get_input(2024, 1).unwrap();
+    server.join().unwrap();
+    (dir, repo, client)
+}
+
+fn stage(repo: &Repository, path: &str) {
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(path)).unwrap();
+    index.write().unwrap();
+}
+
+#[test]
+fn cache_and_dotenv_should_be_ignored() {
+    let (dir, repo, client) = repo_with_cache();
+    fs::write(dir.path().join(".env"), "SESSION=abc123\n").unwrap();
+    let leaks = client.find_leaks(&repo).unwrap();
+    assert_eq!(
+        leaks,
+        vec![
+            Leak::NotIgnored(PathBuf::from("cache")),
+            Leak::NotIgnored(PathBuf::from(".env")),
+        ]
+    );
+    assert_eq!(ignore_rules(&leaks), vec!["/cache/", ".env"]);
+
+    fs::write(dir.path().join(".gitignore"), "target/").unwrap();
+    add_ignore_rules(&repo, &ignore_rules(&leaks)).unwrap();
+    assert_eq!(
+        fs::read_to_string(dir.path().join(".gitignore")).unwrap(),
+        "target/\n# AoC asks that inputs are not published\n/cache/\n.env\n"
+    );
+    assert_eq!(client.find_leaks(&repo).unwrap(), vec![]);
+    // the rules are only ever added once
+    add_ignore_rules(&repo, &ignore_rules(&leaks)).unwrap();
+    assert_eq!(
+        fs::read_to_string(dir.path().join(".gitignore"))
+            .unwrap()
+            .matches("/cache/")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn tracked_files_leak_even_when_ignored() {
+    let (dir, repo, client) = repo_with_cache();
+    fs::write(dir.path().join(".env"), "SESSION=abc123\n").unwrap();
+    fs::create_dir_all(dir.path().join("2024/1")).unwrap();
+    // copied without the trailing newline
+    fs::write(dir.path().join("2024/1/input.txt"), "1\n2\n3").unwrap();
+    fs::write(dir.path().join("2024/1/main.rs"), "fn main() {}\n").unwrap();
+    for path in [
+        ".env",
+        "cache/2024/1/input.txt",
+        "2024/1/input.txt",
+        "2024/1/main.rs",
+    ] {
+        stage(&repo, path);
+    }
+    fs::write(dir.path().join(".gitignore"), "/cache/\n.env\n").unwrap();
+    let leaks = client.find_leaks(&repo).unwrap();
+    assert_eq!(
+        leaks,
+        vec![
+            Leak::Dotenv(PathBuf::from(".env")),
+            Leak::Input {
+                path: PathBuf::from("2024/1/input.txt"),
+                input: dir.path().join("cache/2024/1/input.txt"),
+            },
+            Leak::CachedPage(PathBuf::from("cache/2024/1/input.txt")),
+        ]
+    );
+    assert!(matches!(
+        AocError::Leaking(leaks).to_string().lines().next(),
+        Some("refusing to continue because AoC asks that inputs are not published:")
+    ));
+}
+
+#[test]
+fn cache_outside_of_the_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = tempfile::tempdir().unwrap();
+    let repo = Repository::init(dir.path()).unwrap();
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert_eq!(client.find_leaks(&repo).unwrap(), vec![]);
+}
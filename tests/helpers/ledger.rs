@@ -0,0 +1,132 @@
+use super::helpers::*;
+use chrono::{TimeZone, Utc};
+use std::fs;
+use std::path::Path;
+
+fn ledger_with(submissions: &[(&str, Verdict)]) -> (tempfile::TempDir, Ledger) {
+    let dir = tempfile::tempdir().unwrap();
+    let mut ledger = Ledger::load(dir.path().join("answers.toml")).unwrap();
+    for (answer, verdict) in submissions {
+        ledger.record(1, answer, *verdict, Utc::now());
+    }
+    (dir, ledger)
+}
+
+fn refusal(ledger: &Ledger, answer: &str) -> Refusal {
+    match ledger.check(1, answer) {
+        Err(AocError::Refused(refusal)) => refusal,
+        result => panic!("answer should be refused but was {result:?}"),
+    }
+}
+
+#[test]
+fn empty_ledger_allows_anything() {
+    let (_dir, ledger) = ledger_with(&[]);
+    assert!(ledger.check(1, "42").is_ok());
+    assert!(ledger.check(2, "abc").is_ok());
+    assert_eq!(ledger.bounds(1).unwrap(), (None, None));
+}
+
+#[test]
+fn round_trip() {
+    let (dir, mut ledger) = ledger_with(&[]);
+    let submitted = Utc.with_ymd_and_hms(2025, 12, 5, 5, 3, 12).unwrap();
+    ledger.record(1, "100", Verdict::TooHigh, submitted);
+    ledger.record(2, "14", Verdict::Correct, submitted);
+    ledger.save().unwrap();
+
+    let content = fs::read_to_string(dir.path().join("answers.toml")).unwrap();
+    assert!(content.contains("[[part1]]"));
+    assert!(content.contains("submitted = 2025-12-05T05:03:12Z"));
+
+    let ledger = Ledger::load(dir.path().join("answers.toml")).unwrap();
+    assert_eq!(
+        ledger.submissions(1).unwrap(),
+        vec![Submission {
+            answer: "100".to_owned(),
+            verdict: Verdict::TooHigh,
+            submitted,
+        }]
+    );
+    assert_eq!(ledger.submissions(2).unwrap()[0].verdict, Verdict::Correct);
+}
+
+#[test]
+fn bounds_from_verdicts() {
+    let (_dir, ledger) = ledger_with(&[
+        ("10", Verdict::TooLow),
+        ("100", Verdict::TooHigh),
+        ("20", Verdict::TooLow),
+        ("80", Verdict::TooHigh),
+        ("50", Verdict::Incorrect),
+    ]);
+    assert_eq!(ledger.bounds(1).unwrap(), (Some(20), Some(80)));
+    assert_eq!(ledger.bounds(2).unwrap(), (None, None));
+}
+
+#[test]
+fn refuses_known_wrong_answers() {
+    let (_dir, ledger) = ledger_with(&[("abc", Verdict::Incorrect), ("50", Verdict::Incorrect)]);
+    assert_eq!(
+        refusal(&ledger, "abc"),
+        Refusal::KnownWrong(Verdict::Incorrect)
+    );
+    assert_eq!(
+        refusal(&ledger, "50"),
+        Refusal::KnownWrong(Verdict::Incorrect)
+    );
+    assert!(ledger.check(1, "51").is_ok());
+}
+
+#[test]
+fn refuses_answers_outside_bounds() {
+    let (_dir, ledger) = ledger_with(&[("20", Verdict::TooLow), ("80", Verdict::TooHigh)]);
+    assert_eq!(
+        refusal(&ledger, "80"),
+        Refusal::KnownWrong(Verdict::TooHigh)
+    );
+    assert_eq!(refusal(&ledger, "81"), Refusal::TooHigh(80));
+    assert_eq!(refusal(&ledger, "5"), Refusal::TooLow(20));
+    assert!(ledger.check(1, "21").is_ok());
+    assert!(ledger.check(1, "79").is_ok());
+    // bounds only apply to the part they were recorded for
+    assert!(ledger.check(2, "81").is_ok());
+}
+
+#[test]
+fn rate_limits_do_not_count_as_wrong() {
+    let (_dir, ledger) = ledger_with(&[("50", Verdict::RateLimited(Default::default()))]);
+    assert!(ledger.check(1, "50").is_ok());
+}
+
+#[test]
+fn refuses_solved_parts() {
+    let (_dir, ledger) = ledger_with(&[("20", Verdict::TooLow), ("42", Verdict::Correct)]);
+    assert_eq!(
+        refusal(&ledger, "43"),
+        Refusal::AlreadySolved(Some("42".to_owned()))
+    );
+}
+
+#[test]
+fn refuses_parts_solved_elsewhere() {
+    let (_dir, ledger) = ledger_with(&[("42", Verdict::AlreadySolved)]);
+    assert_eq!(refusal(&ledger, "43"), Refusal::AlreadySolved(None));
+    assert_eq!(
+        refusal(&ledger, "43").to_string(),
+        "part was already solved"
+    );
+}
+
+#[test]
+fn ledger_per_profile() {
+    let root = Path::new("/repo");
+    assert_eq!(
+        Ledger::path(root, 2025, 5, None),
+        Path::new("/repo/2025/5/answers.toml")
+    );
+    assert_eq!(
+        Ledger::path(root, 2025, 5, Some("alice")),
+        Path::new("/repo/2025/5/answers.alice.toml")
+    );
+}
@@ -0,0 +1,74 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve};
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn fetches_missing_pages() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input"), (200, "<article>puzzle</article>")]);
+    let client = client(&url, cache.path());
+    assert_eq!(client.prefetch_day(2024, 3).unwrap(), Prefetched::Fetched);
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2024/day/3/input "));
+    assert!(requests[1].starts_with("GET /2024/day/3 "));
+    assert_eq!(client.cache_entries(Some(2024), Some(3)).unwrap().len(), 2);
+    // the day was only downloaded, not opened
+    assert_eq!(client.opened_at(2024, 3), None);
+    client.get_input(2024, 3).unwrap();
+    assert!(client.opened_at(2024, 3).is_some());
+}
+
+#[test]
+fn skips_cached_pages() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input")]);
+    let client = client(&url, cache.path());
+    client.get_input(2024, 3).unwrap();
+    server.join().unwrap();
+
+    // only the puzzle is still missing
+    let (url, server) = serve(&[(200, "<article>puzzle</article>")]);
+    let client = super::client(&url, cache.path());
+    assert_eq!(client.prefetch_day(2024, 3).unwrap(), Prefetched::Fetched);
+    assert_eq!(server.join().unwrap().len(), 1);
+
+    // nothing is listening so any request would fail
+    let client = super::client("http://127.0.0.1:9", cache.path());
+    assert_eq!(client.prefetch_day(2024, 3).unwrap(), Prefetched::Cached);
+}
+
+#[test]
+fn reports_locked_days() {
+    let cache = tempfile::tempdir().unwrap();
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 5, 12, 0, 0).unwrap());
+    let client = client("http://127.0.0.1:9", cache.path()).with_clock(clock);
+    assert_eq!(client.prefetch_day(2025, 6).unwrap(), Prefetched::Locked);
+    assert_eq!(client.cache_entries(None, None).unwrap(), vec![]);
+}
+
+#[test]
+fn reports_unavailable_days() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(400, "Bad request")]);
+    let client = client(&url, cache.path());
+    assert!(matches!(
+        client.prefetch_day(2024, 3).unwrap(),
+        Prefetched::Unavailable(_)
+    ));
+    server.join().unwrap();
+}
+
+#[test]
+fn stops_when_the_session_expired() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    )]);
+    let client = client(&url, cache.path());
+    assert!(matches!(
+        client.prefetch_day(2024, 3),
+        Err(AocError::SessionExpired(_))
+    ));
+    server.join().unwrap();
+}
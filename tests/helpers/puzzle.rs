@@ -0,0 +1,109 @@
+use super::helpers::*;
+
+static PART1_PAGE: &str = include_str!("../fixtures/puzzle_part1.html");
+static BOTH_PARTS_PAGE: &str = include_str!("../fixtures/puzzle.html");
+
+#[test]
+fn count_unlocked_parts() {
+    assert_eq!(count_parts(PART1_PAGE), 1);
+    assert_eq!(count_parts(BOTH_PARTS_PAGE), 2);
+    assert_eq!(count_parts("<html></html>"), 0);
+}
+
+#[test]
+fn part1_markdown() {
+    assert_eq!(
+        puzzle_to_markdown(PART1_PAGE),
+        r#"## --- Day 3: Snow Counting ---
+
+The elves need to count the *snowflakes* that fell on each [sled](https://adventofcode.com/2025/day/1) overnight.
+
+For example:
+
+```
+1 2
+3 4
+```
+
+Each line lists the flakes on a single sled, so the total here is *`10`*. Some of the flakes are `*` shaped.
+
+- A sled with no snow is written as `0`.
+- Sleds never have *negative* snow.
+
+*How many snowflakes are there in total?*
+"#
+    );
+}
+
+#[test]
+fn part2_markdown() {
+    let markdown = puzzle_to_markdown(BOTH_PARTS_PAGE);
+    assert!(markdown.starts_with(&puzzle_to_markdown(PART1_PAGE)));
+    assert!(markdown.ends_with(
+        r#"## --- Part Two ---
+
+The elves realize that each flake counts *twice*. In the example above, that makes *`20`* snowflakes.
+
+*How many snowflakes are there now?*
+"#
+    ));
+    // answers are rendered outside of the articles and should not be included
+    assert!(!markdown.contains("1234"));
+}
+
+#[test]
+fn escapes_markdown_in_text() {
+    let html = r#"<article class="day-desc"><p>2 * 3 is not 2_3 or \n</p></article>"#;
+    assert_eq!(puzzle_to_markdown(html), "2 \\* 3 is not 2\\_3 or \\\\n\n");
+}
+
+#[test]
+fn example_input() {
+    assert_eq!(
+        find_example_input(PART1_PAGE).as_deref(),
+        Some("1 2\n3 4\n")
+    );
+    assert_eq!(
+        find_example_input(BOTH_PARTS_PAGE).as_deref(),
+        Some("1 2\n3 4\n")
+    );
+    assert_eq!(find_example_input("<html></html>"), None);
+}
+
+#[test]
+fn example_input_prefers_introduced_blocks() {
+    let html = r#"<article class="day-desc">
+        <p>The sleds are arranged like this:</p>
+        <pre><code>#.#</code></pre>
+        <p>For example, consider the following list:</p>
+        <pre><code>1
+2</code></pre>
+        <p>After one step, the list looks like this:</p>
+        <pre><code><em>3</em></code></pre>
+    </article>"#;
+    assert_eq!(find_example_input(html).as_deref(), Some("1\n2"));
+}
+
+#[test]
+fn example_answers() {
+    assert_eq!(
+        find_example_answers(PART1_PAGE),
+        vec![Some("10".to_owned())]
+    );
+    assert_eq!(
+        find_example_answers(BOTH_PARTS_PAGE),
+        vec![Some("10".to_owned()), Some("20".to_owned())]
+    );
+    // highlighted code blocks are not answers
+    let html = r#"<article class="day-desc"><pre><code><em>5</em></code></pre></article>"#;
+    assert_eq!(find_example_answers(html), vec![None]);
+}
+
+#[test]
+fn title() {
+    assert_eq!(
+        find_title(BOTH_PARTS_PAGE),
+        Some("Day 3: Snow Counting".to_owned())
+    );
+    assert_eq!(find_title("<main></main>"), None);
+}
@@ -0,0 +1,159 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve};
+use chrono::{TimeDelta, TimeZone, Utc};
+use std::fs;
+
+static STATS_PAGE: &str = include_str!("../fixtures/self.html");
+
+#[test]
+fn parses_personal_stats() {
+    let days = parse_personal_stats(STATS_PAGE).unwrap();
+    assert_eq!(
+        days,
+        vec![
+            DayStats {
+                day: 5,
+                parts: [
+                    Some(PartStats {
+                        time: None,
+                        rank: Some(71280),
+                        score: Some(0)
+                    }),
+                    Some(PartStats {
+                        time: None,
+                        rank: Some(66612),
+                        score: Some(0)
+                    }),
+                ],
+                opened: None,
+            },
+            DayStats {
+                day: 2,
+                parts: [
+                    Some(PartStats {
+                        time: Some(TimeDelta::seconds(12 * 60 + 31)),
+                        rank: Some(4123),
+                        score: Some(0)
+                    }),
+                    None,
+                ],
+                opened: None,
+            },
+            DayStats {
+                day: 1,
+                parts: [
+                    Some(PartStats {
+                        time: Some(TimeDelta::seconds(200)),
+                        rank: Some(87),
+                        score: Some(14)
+                    }),
+                    Some(PartStats {
+                        time: Some(TimeDelta::seconds(310)),
+                        rank: Some(65),
+                        score: Some(36)
+                    }),
+                ],
+                opened: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parses_stats_without_ranks() {
+    let page = "<main><article><pre>Day   Part 1   Part 2\n  3   01:00:00        -\n</pre></article></main>";
+    let days = parse_personal_stats(page).unwrap();
+    assert_eq!(
+        days[0].parts,
+        [
+            Some(PartStats {
+                time: Some(TimeDelta::hours(1)),
+                rank: None,
+                score: None
+            }),
+            None
+        ]
+    );
+}
+
+#[test]
+fn no_stars_yet() {
+    let page = "<main><article><p>You haven't collected any stars.</p></article></main>";
+    assert_eq!(parse_personal_stats(page).unwrap(), vec![]);
+}
+
+#[test]
+fn records_when_puzzles_are_opened() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input"), (200, "<article>puzzle</article>")]);
+    let opened = Utc.with_ymd_and_hms(2024, 12, 1, 5, 1, 0).unwrap();
+    let clock = FakeClock::at(opened);
+    let client = client(&url, cache.path()).with_clock(clock.clone());
+    client.get_input(2024, 1).unwrap();
+    *clock.now.borrow_mut() += TimeDelta::hours(1);
+    client.get_puzzle_page(2024, 1).unwrap();
+    server.join().unwrap();
+    assert_eq!(client.opened_at(2024, 1), Some(opened));
+    assert_eq!(
+        fs::read_to_string(cache.path().join("2024/1/opened.txt")).unwrap(),
+        "2024-12-01T05:01:00Z"
+    );
+    assert_eq!(client.opened_at(2024, 2), None);
+}
+
+#[test]
+fn caching_the_input_does_not_open_the_puzzle() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input")]);
+    let client = client(&url, cache.path());
+    assert_eq!(client.cache_input(2024, 1).unwrap(), "input\n");
+    assert_eq!(client.opened_at(2024, 1), None);
+    // solving it later still counts, without fetching it again
+    client.get_input(2024, 1).unwrap();
+    server.join().unwrap();
+    assert!(client.opened_at(2024, 1).is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_to_record_opened_is_not_an_error() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input")]);
+    let client = client(&url, cache.path());
+    // a dangling link which cannot be written through
+    fs::create_dir_all(cache.path().join("2024/1")).unwrap();
+    std::os::unix::fs::symlink("missing/opened.txt", cache.path().join("2024/1/opened.txt"))
+        .unwrap();
+    assert_eq!(client.get_input(2024, 1).unwrap(), "input\n");
+    server.join().unwrap();
+    assert_eq!(client.opened_at(2024, 1), None);
+}
+
+#[test]
+fn solve_durations() {
+    let cache = tempfile::tempdir().unwrap();
+    let (url, server) = serve(&[(200, "input"), (200, STATS_PAGE)]);
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2024, 12, 1, 5, 1, 0).unwrap());
+    let client = client(&url, cache.path()).with_clock(clock.clone());
+    client.get_input(2024, 1).unwrap();
+    let days = client.get_personal_stats(2024).unwrap();
+    let requests = server.join().unwrap();
+    assert!(requests[1].starts_with("GET /2024/leaderboard/self "));
+
+    let day1 = days.iter().find(|day| day.day == 1).unwrap();
+    assert_eq!(day1.solve_duration(2024, 1), Some(TimeDelta::seconds(140)));
+    assert_eq!(day1.solve_duration(2024, 2), Some(TimeDelta::seconds(250)));
+    // never opened here, or over 24 hours
+    let day2 = days.iter().find(|day| day.day == 2).unwrap();
+    assert_eq!(day2.solve_duration(2024, 1), None);
+    assert_eq!(
+        render_personal_stats(2024, &days),
+        "Personal stats for 2024 (solved in is since the puzzle was first opened)
+
+Day  Opened                  Part 1    Rank  Score  Solved in    Part 2    Rank  Score  Solved in
+  5  -                         >24h   71280      0          -      >24h   66612      0          -
+  2  -                     00:12:31    4123      0          -         -       -      -          -
+  1  2024-12-01T05:01:00Z  00:03:20      87     14   00:02:20  00:05:10      65     36   00:04:10
+"
+    );
+}
@@ -0,0 +1,141 @@
+use super::helpers::*;
+use super::{client, serve};
+use std::time::Duration;
+
+fn answer_page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><body><main><article><p>{message}</p></article></main></body></html>"
+    )
+}
+
+#[test]
+fn parse_correct() {
+    let html = answer_page(
+        "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+    );
+    assert_eq!(parse_verdict(&html).unwrap(), Verdict::Correct);
+}
+
+#[test]
+fn parse_too_high() {
+    let html = answer_page(
+        "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+    );
+    assert_eq!(parse_verdict(&html).unwrap(), Verdict::TooHigh);
+}
+
+#[test]
+fn parse_too_low() {
+    let html = answer_page(
+        "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+    );
+    assert_eq!(parse_verdict(&html).unwrap(), Verdict::TooLow);
+}
+
+#[test]
+fn parse_incorrect() {
+    let html = answer_page(
+        "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+    );
+    assert_eq!(parse_verdict(&html).unwrap(), Verdict::Incorrect);
+}
+
+#[test]
+fn parse_already_solved() {
+    let html =
+        answer_page("You don't seem to be solving the right level.  Did you already complete it?");
+    assert_eq!(parse_verdict(&html).unwrap(), Verdict::AlreadySolved);
+}
+
+#[test]
+fn parse_rate_limited() {
+    let html = answer_page(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+    );
+    assert_eq!(
+        parse_verdict(&html).unwrap(),
+        Verdict::RateLimited(Duration::from_secs(65))
+    );
+    let html = answer_page(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.",
+    );
+    assert_eq!(
+        parse_verdict(&html).unwrap(),
+        Verdict::RateLimited(Duration::from_secs(39))
+    );
+}
+
+#[test]
+fn parse_rate_limited_with_unknown_unit() {
+    let html = answer_page(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 5秒 left to wait.",
+    );
+    assert!(parse_verdict(&html).is_err());
+}
+
+#[test]
+fn parse_unrecognized() {
+    assert!(parse_verdict("<html></html>").is_err());
+    assert!(parse_verdict(&answer_page("Something unexpected")).is_err());
+}
+
+#[test]
+fn post_answer_to_server() {
+    let (url, server) = serve(&[(200, &answer_page("That's the right answer!"))]);
+    let cache = tempfile::tempdir().unwrap();
+    let verdict = client(&url, cache.path())
+        .post_answer(2025, 5, 2, "14")
+        .unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /2025/day/5/answer HTTP/1.1"));
+    assert!(requests[0].contains("cookie: session=abc123"));
+    assert!(requests[0].ends_with("level=2&answer=14"));
+}
+
+#[test]
+fn submit_answer_refuses_known_wrong_answers() {
+    let (url, server) = serve(&[(
+        200,
+        &answer_page("That's not the right answer; your answer is too high."),
+    )]);
+    let root = tempfile::tempdir().unwrap();
+    let client = client(&url, root.path());
+    assert_eq!(
+        client.submit_answer(2025, 5, 1, 20).unwrap(),
+        Verdict::TooHigh
+    );
+    assert!(root.path().join("2025/5/answers.toml").is_file());
+    assert!(matches!(
+        client.submit_answer(2025, 5, 1, 20),
+        Err(AocError::Refused(Refusal::KnownWrong(Verdict::TooHigh)))
+    ));
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn post_answer_rejects_invalid_part() {
+    let cache = tempfile::tempdir().unwrap();
+    let client = client("http://127.0.0.1:9", cache.path());
+    assert!(client.post_answer(2025, 5, 3, "14").is_err());
+    // the last day only has one part
+    assert!(matches!(
+        client.post_answer(2024, 25, 2, "14"),
+        Err(AocError::Config(_))
+    ));
+}
+
+#[test]
+fn post_answer_offline() {
+    let cache = tempfile::tempdir().unwrap();
+    let client = Client::from_vars(|key| match key {
+        "AOC_OFFLINE" => Some("1".to_owned()),
+        "AOC_CACHE_DIR" => Some(cache.path().to_str().unwrap().to_owned()),
+        _ => None,
+    })
+    .unwrap();
+    let error = client.post_answer(2025, 5, 1, "14").unwrap_err();
+    assert!(error.to_string().contains("AOC_OFFLINE"));
+}
@@ -0,0 +1,81 @@
+use super::helpers::*;
+use super::{FakeClock, client, serve};
+use chrono::{TimeDelta, TimeZone, Utc};
+use std::time::Duration;
+
+#[test]
+fn event_lengths() {
+    assert_eq!(days_in_event(2014), None);
+    assert_eq!(days_in_event(2015), Some(25));
+    assert_eq!(days_in_event(2024), Some(25));
+    assert_eq!(days_in_event(2025), Some(12));
+    assert_eq!(days_in_event(2026), Some(12));
+}
+
+#[test]
+fn unlock_times() {
+    assert_eq!(
+        unlock_time(2025, 1).unwrap(),
+        Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        unlock_time(2024, 25).unwrap(),
+        Utc.with_ymd_and_hms(2024, 12, 25, 5, 0, 0).unwrap()
+    );
+    assert!(matches!(
+        unlock_time(2025, 13),
+        Err(AocError::NoSuchDay {
+            year: 2025,
+            day: 13
+        })
+    ));
+    assert!(unlock_time(2024, 0).is_err());
+    assert!(unlock_time(2014, 1).is_err());
+}
+
+#[test]
+fn refuses_to_fetch_before_unlock() {
+    let cache = tempfile::tempdir().unwrap();
+    // nothing is listening so any request would fail differently
+    let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 5, 4, 59, 59).unwrap());
+    let client = client("http://127.0.0.1:9", cache.path()).with_clock(clock.clone());
+    assert!(matches!(
+        client.get_input(2025, 5),
+        Err(AocError::PuzzleNotUnlocked { year: 2025, day: 5 })
+    ));
+    assert!(matches!(
+        client.get_puzzle_page(2025, 5),
+        Err(AocError::PuzzleNotUnlocked { year: 2025, day: 5 })
+    ));
+    assert!(matches!(
+        client.post_answer(2025, 5, 1, "14"),
+        Err(AocError::PuzzleNotUnlocked { year: 2025, day: 5 })
+    ));
+    assert!(clock.sleeps.borrow().is_empty());
+    // nothing should have been cached either
+    assert_eq!(client.cache_entries(None, None).unwrap(), vec![]);
+}
+
+#[test]
+fn waits_for_unlock() {
+    let (url, server) = serve(&[(200, "1 2\n")]);
+    let cache = tempfile::tempdir().unwrap();
+    let clock = FakeClock::at(
+        Utc.with_ymd_and_hms(2025, 12, 5, 4, 59, 57).unwrap() + TimeDelta::milliseconds(500),
+    );
+    let client = client(&url, cache.path())
+        .with_clock(clock.clone())
+        .wait_for_unlock(true);
+    assert_eq!(client.get_input(2025, 5).unwrap(), "1 2\n");
+    server.join().unwrap();
+
+    assert_eq!(
+        *clock.sleeps.borrow(),
+        vec![
+            Duration::from_secs(1),
+            Duration::from_secs(1),
+            Duration::from_millis(500)
+        ]
+    );
+    assert_eq!(clock.now(), unlock_time(2025, 5).unwrap());
+}